@@ -0,0 +1,169 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::PathBuf,
+};
+
+use toml::{Table, Value};
+
+use crate::{input::input_dir, Answer};
+
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers keyed by day, input label and part, as stored in
+/// `inputs/answers.toml`:
+///
+/// ```toml
+/// [day05.real]
+/// part1 = 196167384
+/// part2 = 125742456
+/// ```
+#[derive(Debug, Default)]
+pub struct AnswerBook(BTreeMap<(u8, String), [Option<Answer>; 2]>);
+
+#[derive(Debug)]
+pub enum AnswerError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// The file is valid TOML but does not follow the `[dayNN.label]` layout.
+    Layout {
+        key: String,
+        reason: &'static str,
+    },
+}
+
+impl Display for AnswerError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AnswerError::Io { path, source } => {
+                write!(f, "cannot read {}: {source}", path.display())
+            }
+            AnswerError::Toml { path, source } => {
+                write!(f, "{} is invalid: {source}", path.display())
+            }
+            AnswerError::Layout { key, reason } => write!(f, "invalid answer `{key}`: {reason}"),
+        }
+    }
+}
+
+impl Error for AnswerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswerError::Io { source, .. } => Some(source),
+            AnswerError::Toml { source, .. } => Some(source),
+            AnswerError::Layout { .. } => None,
+        }
+    }
+}
+
+impl AnswerBook {
+    /// Reads `answers.toml` from the input directory.
+    pub fn load() -> Result<Self, AnswerError> {
+        let path = input_dir().join(ANSWERS_FILE);
+        let content = fs::read_to_string(&path)
+            .map_err(|source| AnswerError::Io { path: path.clone(), source })?;
+        let table =
+            content.parse::<Table>().map_err(|source| AnswerError::Toml { path, source })?;
+        Self::from_table(table)
+    }
+
+    fn from_table(table: Table) -> Result<Self, AnswerError> {
+        let layout = |key: String, reason| AnswerError::Layout { key, reason };
+
+        let mut answers = BTreeMap::new();
+        for (day_key, labels) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| layout(day_key.clone(), "expected a table named like `day05`"))?;
+            let Value::Table(labels) = labels else {
+                return Err(layout(day_key, "expected a table of input labels"));
+            };
+
+            for (label, parts) in labels {
+                let key = format!("{day_key}.{label}");
+                let Value::Table(parts) = parts else {
+                    return Err(layout(key, "expected a table with `part1` and/or `part2`"));
+                };
+
+                let mut expected = [None, None];
+                for (part, answer) in parts {
+                    let invalid = |reason| layout(format!("{key}.{part}"), reason);
+                    let index = match part.as_str() {
+                        "part1" => 0,
+                        "part2" => 1,
+                        _ => return Err(invalid("expected `part1` or `part2`")),
+                    };
+                    expected[index] = Some(match answer {
+                        Value::Integer(answer) => Answer::from(answer),
+                        Value::String(answer) => Answer::from(answer),
+                        _ => return Err(invalid("expected an integer or a string")),
+                    });
+                }
+                answers.insert((day, label), expected);
+            }
+        }
+
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, day: u8, label: &str, part: u8) -> Option<&Answer> {
+        let expected = self.0.get(&(day, label.to_string()))?;
+        expected[usize::from(part) - 1].as_ref()
+    }
+
+    /// Input labels of the day that have at least one known answer.
+    pub fn labels(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.0
+            .keys()
+            .filter(move |(answer_day, _)| *answer_day == day)
+            .map(|(_, label)| label.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<AnswerBook, AnswerError> {
+        AnswerBook::from_table(content.parse().unwrap())
+    }
+
+    #[test]
+    fn lookup_by_day_label_and_part() {
+        let answers = parse(
+            r#"
+            [day05.real]
+            part1 = 196167384
+            [day19.test]
+            part2 = "167409079868000"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(5, "real", 1), Some(&Answer::from(196167384)));
+        assert_eq!(answers.get(5, "real", 2), None);
+        assert_eq!(answers.get(19, "test", 2), Some(&Answer::from(167409079868000u64)));
+        assert_eq!(answers.labels(5).collect::<Vec<_>>(), ["real"]);
+    }
+
+    #[test]
+    fn reject_unknown_part() {
+        let result = parse("[day05.real]\npart3 = 1");
+        assert!(matches!(result, Err(AnswerError::Layout { .. })));
+    }
+
+    #[test]
+    fn stored_answers_are_valid() {
+        let answers = AnswerBook::load().unwrap();
+        assert_eq!(answers.get(7, "WJ", 2), Some(&Answer::from(255632664)));
+    }
+}
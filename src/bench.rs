@@ -0,0 +1,214 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::Answer;
+
+#[derive(Clone, Copy)]
+pub struct BenchConfig {
+    /// Runs that are executed but not measured.
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+/// Timing of one part of a solution on one input, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub solution: String,
+    pub part: u8,
+    pub input: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Measurement {
+    pub const CSV_HEADER: &'static str = "solution,part,input,runs,min_ns,median_ns,p95_ns";
+
+    fn new(solution: &str, part: u8, input: &str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            solution: solution.to_string(),
+            part,
+            input: input.to_string(),
+            runs: samples.len(),
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(percentile(&samples, 50)),
+            p95_ns: nanos(percentile(&samples, 95)),
+        }
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.solution,
+            self.part,
+            self.input,
+            self.runs,
+            self.min_ns,
+            self.median_ns,
+            self.p95_ns
+        )
+    }
+
+    fn is_same_benchmark(&self, other: &Measurement) -> bool {
+        self.solution == other.solution && self.part == other.part && self.input == other.input
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (percent * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Runs `solve` `config.warmup + config.runs` times and measures the last `config.runs`.
+pub fn bench_part(
+    solution: &str,
+    part: u8,
+    label: &str,
+    solve: fn(&str) -> Answer,
+    input: &str,
+    config: BenchConfig,
+) -> Measurement {
+    for _ in 0..config.warmup {
+        std::hint::black_box(solve(input));
+    }
+
+    let samples = (0..config.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(solve(input));
+            start.elapsed()
+        })
+        .collect_vec();
+
+    Measurement::new(solution, part, label, samples)
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io { path: PathBuf, source: io::Error },
+    Json { path: PathBuf, source: serde_json::Error },
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BaselineError::Io { path, source } => {
+                write!(f, "cannot access baseline {}: {source}", path.display())
+            }
+            BaselineError::Json { path, source } => {
+                write!(f, "{} is not a benchmark baseline: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BaselineError::Io { source, .. } => Some(source),
+            BaselineError::Json { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Reads a baseline written by `aoc bench --format json`.
+pub fn load_baseline(path: &Path) -> Result<Vec<Measurement>, BaselineError> {
+    let content = fs::read_to_string(path)
+        .map_err(|source| BaselineError::Io { path: path.to_path_buf(), source })?;
+    serde_json::from_str(&content)
+        .map_err(|source| BaselineError::Json { path: path.to_path_buf(), source })
+}
+
+/// Median of a measurement compared to the same benchmark in the baseline.
+pub struct Comparison<'a> {
+    pub current: &'a Measurement,
+    pub baseline_median_ns: u64,
+}
+
+impl Comparison<'_> {
+    /// Relative change of the median, e.g. `0.25` when 25% slower.
+    pub fn change(&self) -> f64 {
+        self.current.median_ns as f64 / self.baseline_median_ns.max(1) as f64 - 1.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Pairs every measurement with its baseline. Benchmarks missing from the
+/// baseline are skipped.
+pub fn compare<'a>(current: &'a [Measurement], baseline: &[Measurement]) -> Vec<Comparison<'a>> {
+    current
+        .iter()
+        .filter_map(|measurement| {
+            let previous =
+                baseline.iter().find(|previous| previous.is_same_benchmark(measurement))?;
+            Some(Comparison { current: measurement, baseline_median_ns: previous.median_ns })
+        })
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(solution: &str, samples_ms: &[u64]) -> Measurement {
+        let samples = samples_ms.iter().map(|ms| Duration::from_millis(*ms)).collect_vec();
+        Measurement::new(solution, 1, "real", samples)
+    }
+
+    #[test]
+    fn statistics() {
+        let measurement = measurement("day01", &(1..=20).rev().collect_vec());
+        assert_eq!(measurement.runs, 20);
+        assert_eq!(measurement.min_ns, 1_000_000);
+        assert_eq!(measurement.median_ns, 10_000_000);
+        assert_eq!(measurement.p95_ns, 19_000_000);
+    }
+
+    #[test]
+    fn single_run() {
+        let measurement = measurement("day01", &[7]);
+        assert_eq!(measurement.median_ns, 7_000_000);
+        assert_eq!(measurement.p95_ns, 7_000_000);
+    }
+
+    #[test]
+    fn flag_regressions_beyond_threshold() {
+        let baseline = [measurement("day05", &[100]), measurement("day05b", &[100])];
+        let current = [
+            measurement("day05", &[130]),
+            measurement("day05b", &[105]),
+            measurement("day05c", &[1]),
+        ];
+
+        let comparisons = compare(&current, &baseline);
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(comparisons[0].is_regression(0.1));
+        assert!(!comparisons[1].is_regression(0.1));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let measurements = vec![measurement("day05c", &[3, 1, 2])];
+        let json = serde_json::to_string(&measurements).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Measurement>>(&json).unwrap(), measurements);
+    }
+}
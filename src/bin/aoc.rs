@@ -0,0 +1,548 @@
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::Read,
+    ops::Range,
+    panic,
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc2023::{
+    bench::{bench_part, compare, load_baseline, BenchConfig, Measurement},
+    days::{day05, registry},
+    generate::generator,
+    input::available_inputs,
+    read_input, read_input_from, Answer, AnswerBook, Entry, InputSource, InputType,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day, or of `all` days
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Also print how each part was solved, for days that support it
+        #[arg(long)]
+        render: bool,
+    },
+    /// Time the selected solutions over several runs
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Number of measured runs per part
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+
+        /// Number of unmeasured runs before measuring
+        #[arg(long, default_value_t = 2)]
+        warmup: usize,
+
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+
+        /// Write the measurements to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Compare the medians against measurements saved with `--format json`
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Flag parts whose median is more than this many percent slower than the baseline
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check every solution and variant against the known answers in `inputs/answers.toml`
+    Verify {
+        /// Day number or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+
+        /// Give up on a part after this many seconds
+        #[arg(long, default_value_t = 30)]
+        timeout: u64,
+    },
+    /// Print a random input for a day, e.g. to pipe into `run --input -`
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How large the input is, in a unit that depends on the day. Defaults
+        /// to about the size of the real input
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        size: Option<u64>,
+    },
+    /// Print the day 5 seeds whose locations fall in a range, and which of
+    /// them are planted
+    Seeds {
+        /// Locations as `START..END`, the end excluded
+        #[arg(value_parser = parse_range)]
+        locations: Range<i64>,
+
+        /// Input label, a path to a file, or `-` for stdin, as for `run`
+        #[arg(long, default_value = "real")]
+        input: String,
+    },
+    /// List registered solutions
+    List,
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Day number or `all`
+    day: DaySelection,
+
+    /// Run only this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run a named alternate implementation instead of the main one
+    #[arg(long, conflicts_with = "all_variants")]
+    variant: Option<String>,
+
+    /// Run the main implementation and every registered variant
+    #[arg(long)]
+    all_variants: bool,
+
+    /// Input label, e.g. `real`, `test` or `WJ` for `inputs/dayNN_WJ.txt`, a path
+    /// to a file, or `-` for stdin. Labels are looked up in `$AOC_INPUT_DIR` if set.
+    #[arg(long, default_value = "real")]
+    input: String,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl DaySelection {
+    fn contains(self, day: u8) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Day(selected) => selected == day,
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "all" => Ok(DaySelection::All),
+            day => match day.parse() {
+                Ok(day @ 1..=25) => Ok(DaySelection::Day(day)),
+                _ => Err(format!("expected a day between 1 and 25 or `all`, got `{day}`")),
+            },
+        }
+    }
+}
+
+fn parse_range(value: &str) -> Result<Range<i64>, String> {
+    let expected = || format!("expected a range like `46..56`, got `{value}`");
+    let (start, end) = value.split_once("..").ok_or_else(expected)?;
+    match (start.trim().parse(), end.trim().parse()) {
+        (Ok(start), Ok(end)) if start < end => Ok(start..end),
+        _ => Err(expected()),
+    }
+}
+
+/// Answers which seeds of the almanac end up at one of `locations`. Returns
+/// whether the almanac could be read.
+fn seeds(locations: Range<i64>, input: &str) -> bool {
+    let almanac = read_input_from(5, InputSource::from(input))
+        .map_err(|err| err.to_string())
+        .and_then(|input| input.parse::<day05::Almanac>().map_err(|err| err.to_string()));
+    match almanac {
+        Ok(almanac) => {
+            println!("{}", day05::render_seeds_landing_in(&almanac, locations));
+            true
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        }
+    }
+}
+
+/// Prints the answer of one part. Returns whether the part ran without panicking.
+fn run_part(entry: &Entry, part: u8, input: &str) -> bool {
+    let solve = if part == 1 { entry.part1 } else { entry.part2 };
+
+    match panic::catch_unwind(|| solve(input)) {
+        Ok(answer) => {
+            println!("Part{part}: {answer}");
+            true
+        }
+        Err(_) => {
+            println!("Part{part}: panicked");
+            false
+        }
+    }
+}
+
+impl Selection {
+    fn parts(&self) -> impl Iterator<Item = u8> + '_ {
+        [1, 2].into_iter().filter(|current| self.part.is_none_or(|part| part == *current))
+    }
+
+    /// Calls `f` with every selected solution and the input of its day, if the
+    /// solution can parse it. Returns whether every input was read and parsed.
+    fn for_each_entry(&self, mut f: impl FnMut(&Entry, &str)) -> bool {
+        let entries = registry()
+            .into_iter()
+            .filter(|entry| self.day.contains(entry.day))
+            .filter(|entry| self.all_variants || entry.variant == self.variant.as_deref())
+            .collect_vec();
+
+        if entries.is_empty() {
+            eprintln!("No registered solution matches the selection");
+            return false;
+        }
+
+        // Stdin can only be read once, so an input that is not stored per day is
+        // read up front and shared by every selected day.
+        let shared_input = match InputSource::from(self.input.as_str()) {
+            InputSource::Stored(_) => None,
+            source => match read_input_from(entries[0].day, source) {
+                Ok(input) => Some(input),
+                Err(err) => {
+                    eprintln!("{err}");
+                    return false;
+                }
+            },
+        };
+
+        let mut all_parsed = true;
+        for (day, entries) in &entries.iter().group_by(|entry| entry.day) {
+            let input = match &shared_input {
+                Some(input) => input.clone(),
+                None => match read_input_from(day, InputSource::from(self.input.as_str())) {
+                    Ok(input) => input,
+                    Err(err) => {
+                        eprintln!("{err}");
+                        all_parsed = false;
+                        continue;
+                    }
+                },
+            };
+
+            // A malformed input is reported with its location rather than
+            // left to panic somewhere inside a part.
+            for entry in entries {
+                match (entry.check)(&input) {
+                    Ok(()) => f(entry, &input),
+                    Err(err) => {
+                        eprintln!("{err}");
+                        all_parsed = false;
+                    }
+                }
+            }
+        }
+
+        all_parsed
+    }
+}
+
+/// Returns whether every selected part ran, which fails on an unreadable or
+/// malformed input as well as on a panic.
+fn run(selection: &Selection, render: bool) -> bool {
+    let mut all_ran = true;
+    let all_parsed = selection.for_each_entry(|entry, input| {
+        println!("{}", entry.name());
+        for part in selection.parts() {
+            all_ran &= run_part(entry, part, input);
+            if render {
+                all_ran &= render_part(entry, part, input);
+            }
+        }
+    });
+    all_parsed && all_ran
+}
+
+/// Returns whether rendering did not panic. A solution without a picture is
+/// not a failure.
+fn render_part(entry: &Entry, part: u8, input: &str) -> bool {
+    match panic::catch_unwind(|| (entry.render)(input, part)) {
+        Ok(Some(picture)) => println!("{}", picture.trim_end()),
+        Ok(None) => eprintln!("{} has nothing to render", entry.name()),
+        Err(_) => {
+            eprintln!("{} panicked while rendering part {part}", entry.name());
+            return false;
+        }
+    }
+    true
+}
+
+fn bench(selection: &Selection, config: BenchConfig) -> Vec<Measurement> {
+    let mut measurements = vec![];
+    selection.for_each_entry(|entry, input| {
+        for part in selection.parts() {
+            let solve = if part == 1 { entry.part1 } else { entry.part2 };
+            let name = entry.name();
+            let label = &selection.input;
+            match panic::catch_unwind(|| bench_part(&name, part, label, solve, input, config)) {
+                Ok(measurement) => measurements.push(measurement),
+                Err(_) => eprintln!("{name} part{part} panicked, skipping"),
+            }
+        }
+    });
+    measurements
+}
+
+fn format_measurements(measurements: &[Measurement], format: Format) -> String {
+    match format {
+        Format::Table => {
+            let mut table = format!(
+                "{:<10} {:<5} {:<8} {:>5} {:>12} {:>12} {:>12}\n",
+                "solution", "part", "input", "runs", "min", "median", "p95"
+            );
+            for m in measurements {
+                let [min, median, p95] = [m.min_ns, m.median_ns, m.p95_ns]
+                    .map(|ns| format!("{:.2?}", Duration::from_nanos(ns)));
+                table += &format!(
+                    "{:<10} {:<5} {:<8} {:>5} {min:>12} {median:>12} {p95:>12}\n",
+                    m.solution, m.part, m.input, m.runs
+                );
+            }
+            table
+        }
+        Format::Json => {
+            serde_json::to_string_pretty(measurements).expect("measurements serialize") + "\n"
+        }
+        Format::Csv => {
+            let rows = measurements.iter().map(Measurement::to_csv);
+            [Measurement::CSV_HEADER.to_string()].into_iter().chain(rows).join("\n") + "\n"
+        }
+    }
+}
+
+/// Prints the change of every median against the baseline to stderr, keeping
+/// stdout machine-readable. Returns whether any part regressed.
+fn report_regressions(measurements: &[Measurement], baseline: &Path, threshold: f64) -> bool {
+    let baseline = match load_baseline(baseline) {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("{err}");
+            return true;
+        }
+    };
+
+    let mut regressed = false;
+    for comparison in compare(measurements, &baseline) {
+        let m = comparison.current;
+        let is_regression = comparison.is_regression(threshold / 100.0);
+        regressed |= is_regression;
+        eprintln!(
+            "{:<10} part{} {:<8} {:>+8.1}%{}",
+            m.solution,
+            m.part,
+            m.input,
+            comparison.change() * 100.0,
+            if is_regression { "  REGRESSION" } else { "" }
+        );
+    }
+    regressed
+}
+
+enum Check {
+    Pass,
+    Fail {
+        expected: Answer,
+        answer: Answer,
+    },
+    Panicked,
+    TimedOut,
+    /// No known answer for this input and part.
+    Missing,
+    /// A known answer exists but its input file does not.
+    NoInput,
+}
+
+impl Check {
+    fn is_failure(&self) -> bool {
+        matches!(self, Check::Fail { .. } | Check::Panicked | Check::TimedOut)
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let check = match self {
+            Check::Pass => "pass",
+            Check::Fail { .. } => "FAIL",
+            Check::Panicked => "PANIC",
+            Check::TimedOut => "TIMEOUT",
+            Check::Missing => "-",
+            Check::NoInput => "no input",
+        };
+        f.pad(check)
+    }
+}
+
+/// Runs the part in a child `aoc run` process, so a panicking or runaway
+/// solution can be killed without stopping the verification.
+fn check_part(entry: &Entry, label: &str, part: u8, expected: &Answer, timeout: Duration) -> Check {
+    let mut command = process::Command::new(env::current_exe().expect("cannot locate aoc"));
+    command.args(["run", &entry.day.to_string(), "--part", &part.to_string(), "--input", label]);
+    if let Some(variant) = entry.variant {
+        command.args(["--variant", variant]);
+    }
+
+    let Ok(mut child) = command.stdout(Stdio::piped()).stderr(Stdio::null()).spawn() else {
+        return Check::Panicked;
+    };
+
+    // Drain stdout while waiting, so a chatty solution cannot block on a full pipe.
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+
+    let started = Instant::now();
+    while child.try_wait().is_ok_and(|status| status.is_none()) {
+        if started.elapsed() > timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Check::TimedOut;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    let output = reader.join().unwrap_or_default();
+    let prefix = format!("Part{part}: ");
+    match output.lines().find_map(|line| line.strip_prefix(&prefix)) {
+        Some("panicked") | None => Check::Panicked,
+        Some(answer) if Answer::from(answer) == *expected => Check::Pass,
+        Some(answer) => Check::Fail { expected: expected.clone(), answer: Answer::from(answer) },
+    }
+}
+
+/// Prints a pass/fail matrix of every solution and input label of the selected
+/// days. Returns whether all checks with a known answer passed.
+fn verify(day: DaySelection, timeout: Duration) -> bool {
+    let answers = match AnswerBook::load() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    println!("{:<10} {:<8} {:<8} {:<8}", "solution", "input", "part1", "part2");
+
+    let mut rows = vec![];
+    for entry in registry().into_iter().filter(|entry| day.contains(entry.day)) {
+        let labels = available_inputs(entry.day)
+            .into_iter()
+            .chain(answers.labels(entry.day).map(str::to_string))
+            .sorted()
+            .dedup();
+
+        for label in labels {
+            let has_input = read_input(entry.day, InputType::from(label.as_str())).is_ok();
+            let checks = [1, 2].map(|part| match answers.get(entry.day, &label, part) {
+                None => Check::Missing,
+                Some(_) if !has_input => Check::NoInput,
+                Some(expected) => check_part(&entry, &label, part, expected, timeout),
+            });
+
+            let [part1, part2] = &checks;
+            println!("{:<10} {label:<8} {part1:<8} {part2:<8}", entry.name());
+            rows.push((entry.name(), label, checks));
+        }
+    }
+
+    let mut all_passed = true;
+    for (name, label, checks) in &rows {
+        for (part, check) in (1..).zip(checks) {
+            if check.is_failure() {
+                all_passed = false;
+            }
+            if let Check::Fail { expected, answer } = check {
+                println!("{name} {label} part{part}: expected {expected}, got {answer}");
+            }
+        }
+    }
+
+    all_passed
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Run { selection, render } => {
+            if !run(&selection, render) {
+                process::exit(1);
+            }
+        }
+        Command::Bench { selection, runs, warmup, format, output, baseline, threshold } => {
+            let measurements = bench(&selection, BenchConfig { warmup, runs });
+
+            let formatted = format_measurements(&measurements, format);
+            match output {
+                Some(path) => {
+                    if let Err(err) = fs::write(&path, formatted) {
+                        eprintln!("cannot write {}: {err}", path.display());
+                        process::exit(1);
+                    }
+                }
+                None => print!("{formatted}"),
+            }
+
+            if let Some(baseline) = baseline {
+                if report_regressions(&measurements, &baseline, threshold) {
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Verify { day, timeout } => {
+            if !verify(day, Duration::from_secs(timeout)) {
+                process::exit(1);
+            }
+        }
+        Command::Generate { day, seed, size } => {
+            let generator = generator(day).expect("every day has a generator");
+            let size = size.map_or(generator.default_size, |size| size as usize);
+            print!("{}", generator.generate(seed, size));
+        }
+        Command::Seeds { locations, input } => {
+            if !seeds(locations, &input) {
+                process::exit(1);
+            }
+        }
+        Command::List => {
+            for entry in registry() {
+                println!("{}", entry.name());
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Cycle detection for deterministic processes.
+//!
+//! A process is a start state and a `step` function. If it only has finitely
+//! many states it must eventually repeat one, after which it runs in a loop
+//! forever: the states are `x_0, x_1, ..., x_(start - 1)` once, followed by
+//! `x_start, ..., x_(start + length - 1)` over and over. Knowing where that
+//! loop starts and how long it is answers "what is the state after a billion
+//! steps" without taking them.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a process starts repeating itself (`mu`) and the length of the
+/// repeating part (`lambda`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare, in constant memory.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Finds the cycle with Brent's algorithm, in constant memory and usually
+/// fewer steps than [`floyd`].
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Runs the process until it repeats a state or reaches step `limit`,
+/// returning every state it went through and the cycle if it found one.
+fn run<S: Clone + Hash + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut history = vec![initial];
+
+    while history.len() <= limit {
+        let next = step(history.last().unwrap());
+        if let Some(start) = seen.get(&next) {
+            let cycle = Cycle { start: *start, length: history.len() - start };
+            return (history, Some(cycle));
+        }
+        seen.insert(next.clone(), history.len());
+        history.push(next);
+    }
+
+    (history, None)
+}
+
+/// Finds the cycle by remembering every state, in a single pass. Also returns
+/// the states up to the end of the first round of the cycle, indexed by step.
+pub fn find<S: Clone + Hash + Eq>(initial: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let (history, cycle) = run(initial, step, usize::MAX);
+    (cycle.unwrap(), history)
+}
+
+/// The state after `n` steps, skipping the rounds of the cycle once it is
+/// found.
+pub fn nth<S: Clone + Hash + Eq>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (mut history, cycle) = run(initial, step, n);
+    match cycle {
+        Some(cycle) => history.swap_remove(cycle.equivalent_step(n)),
+        None => history.swap_remove(n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, then 4..=9 over and over.
+    fn step(n: &u32) -> u32 {
+        if *n == 9 {
+            4
+        } else {
+            n + 1
+        }
+    }
+
+    const EXPECTED: Cycle = Cycle { start: 4, length: 6 };
+
+    #[test]
+    fn algorithms_agree() {
+        assert_eq!(floyd(0, step), EXPECTED);
+        assert_eq!(brent(0, step), EXPECTED);
+
+        let (cycle, history) = find(0, step);
+        assert_eq!(cycle, EXPECTED);
+        assert_eq!(history, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn cycle_from_the_start() {
+        let step = |n: &u32| (n + 1) % 3;
+        let expected = Cycle { start: 0, length: 3 };
+
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(find(0, step).0, expected);
+    }
+
+    #[test]
+    fn jump_to_step() {
+        assert_eq!(nth(0, step, 0), 0);
+        assert_eq!(nth(0, step, 3), 3);
+        assert_eq!(nth(0, step, 10), 4);
+        assert_eq!(nth(0, step, 1_000_000_000), 4);
+        assert_eq!(nth(0, step, 1_000_000_001), 5);
+    }
+}
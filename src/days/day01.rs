@@ -1,20 +1,82 @@
-use aoc2023::{read_input, InputType};
+use crate::{
+    parse::{self, ParseResult},
+    Answer, ParseError, Solution,
+};
 use itertools::Itertools;
-use map_macro::hash_map;
-use timed::timed;
 
 const DAY: u8 = 1;
 
-fn main() {
-    let real_input = read_input(DAY, InputType::Real).unwrap();
+pub struct Day01;
 
-    println!("Part1: {}", part1(&real_input));
-    println!("Part2: {}", part2_v3(&real_input));
+impl Solution for Day01 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2_v3(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_document(input).map(drop)
+    }
+}
+
+pub struct Day01V1;
+
+impl Solution for Day01V1 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2_v1(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_document(input).map(drop)
+    }
+}
+
+pub struct Day01V2;
+
+impl Solution for Day01V2 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2_v2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_document(input).map(drop)
+    }
+}
+
+/// The calibration document, a line of letters and digits per value. Every
+/// line needs a digit, written as one or spelled out, to read its value from.
+fn parse_document(input: &str) -> ParseResult<Vec<&str>> {
+    parse::lines(DAY, input, |parser| {
+        parser.skip_spaces();
+        let position = parser.position();
+        let line = parser.word()?;
+        if (0..line.len()).any(|i| convert_to_digits(&line[i..]).is_some()) {
+            Ok(line)
+        } else {
+            Err(parser.error_at(position, "a line with a digit"))
+        }
+    })
 }
 
 /////////////////////////////////////////
 // Part 1
-#[timed]
 fn part1(input: &str) -> u32 {
     input
         .lines()
@@ -23,8 +85,10 @@ fn part1(input: &str) -> u32 {
                 .chars()
                 .flat_map(|c| c.to_digit(10))
                 .collect::<Vec<u32>>();
-            let number = format!("{}{}", digits.first().unwrap(), digits.last().unwrap());
-            number.parse::<u32>()
+            // Lines that only spell their digits out, like those of the
+            // example of part 2, have no value in part 1.
+            let number = format!("{}{}", digits.first()?, digits.last()?);
+            number.parse::<u32>().ok()
         })
         .sum()
 }
@@ -32,29 +96,20 @@ fn part1(input: &str) -> u32 {
 /////////////////////////////////////////
 // Part 2 v1
 
-// fn replaceStrToDigits(input: &str) -> String {
-//     let mut hello = hash_map! {
-//         "one" => input.find("one"),
-//         "two" => input.find("two"),
-//         "three" => input.find("three"),
-//         "four" => input.find("four"),
-//         "five" => input.find("five"),
-//         "six" => input.find("six"),
-//         "seven" => input.find("seven"),
-//         "eight" => input.find("eight"),
-//         "nine" => input.find("nine"),
-//     }
-//     .iter()
-//     .map(|(k, v)| v.map(|x| x as f32).or_else(|| Some(f32::INFINITY)).unwrap())
-//     .sorted_by(|a, b| a.partial_cmp(b).unwrap());
-
-//     let new_string = input.to_owned();
-
-//     while hello.any(|v| v != f32::INFINITY) {
-//         new_string.replace(from, to)
-//     }
-//     "".to_owned()
-// }
+/// Spelled-out digits and what [`part2_v1`] replaces them with. The first and
+/// last letters are kept, as neighbouring words can share them, like the `t`
+/// of `eightwo`.
+const SPELLED_DIGITS: [(&str, &str); 9] = [
+    ("one", "o1e"),
+    ("two", "t2o"),
+    ("three", "t3e"),
+    ("four", "f4r"),
+    ("five", "f5e"),
+    ("six", "s6x"),
+    ("seven", "s7n"),
+    ("eight", "e8t"),
+    ("nine", "n9e"),
+];
 
 fn my_replace(line: &str, current_index: usize, str_num: &str, real_num: &str) -> String {
     if let Some(index) = line.find(str_num) {
@@ -65,43 +120,24 @@ fn my_replace(line: &str, current_index: usize, str_num: &str, real_num: &str) -
     line.to_owned()
 }
 
-#[timed]
 fn part2_v1(input: &str) -> u32 {
     input
         .lines()
         .map(|line| {
-            // println!("{}", line);
-            // line.replace("one", "1")
-            //     .replace("two", "2")
-            //     .replace("three", "3")
-            //     .replace("four", "4")
-            //     .replace("five", "5")
-            //     .replace("six", "6")
-            //     .replace("seven", "7")
-            //     .replace("eight", "8")
-            //     .replace("nine", "9")
             let mut new_line = line.to_owned();
             for i in 0..new_line.len() {
-                new_line = my_replace(&new_line, i, "one", "1");
-                new_line = my_replace(&new_line, i, "two", "2");
-                new_line = my_replace(&new_line, i, "three", "3");
-                new_line = my_replace(&new_line, i, "four", "4");
-                new_line = my_replace(&new_line, i, "five", "5");
-                new_line = my_replace(&new_line, i, "six", "6");
-                new_line = my_replace(&new_line, i, "seven", "7");
-                new_line = my_replace(&new_line, i, "eight", "8");
-                new_line = my_replace(&new_line, i, "nine", "9");
+                for (str_num, real_num) in SPELLED_DIGITS {
+                    new_line = my_replace(&new_line, i, str_num, real_num);
+                }
             }
             new_line
         })
         .flat_map(|line| {
-            // println!("{}", line);
             let digits = line
                 .chars()
                 .flat_map(|c| c.to_digit(10))
                 .collect::<Vec<u32>>();
             let number = format!("{}{}", digits.first().unwrap(), digits.last().unwrap());
-            // println!("{}\n", number);
             number.parse::<u32>()
         })
         .sum()
@@ -147,7 +183,6 @@ fn convert2(line: &str, current_index: usize) -> Option<u32> {
     .next()
 }
 
-#[timed]
 fn part2_v2(input: &str) -> u32 {
     input
         .lines()
@@ -186,7 +221,6 @@ fn convert_to_digits(slice: &str) -> Option<u32> {
     })
 }
 
-#[timed]
 fn part2_v3(input: &str) -> u32 {
     input
         .lines()
@@ -202,6 +236,7 @@ fn part2_v3(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{read_input, InputType};
 
     fn get_test_input1() -> String {
         read_input(DAY, InputType::Test).unwrap()
@@ -226,44 +261,36 @@ mod tests {
     }
 
     #[test]
-    fn part2_dp() {
-        let expected = 54728;
-        let input = read_input(DAY, InputType::Other("DP".to_owned())).unwrap();
-        let result = part2_v3(&input);
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn part2_mn() {
-        let expected = 53389;
-        let input = read_input(DAY, InputType::Other("MN".to_owned())).unwrap();
-        let result = part2_v3(&input);
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn part2_wj() {
-        let expected = 54265;
-        let input = read_input(DAY, InputType::Other("WJ".to_owned())).unwrap();
-        let result = part2_v3(&input);
-        assert_eq!(result, expected);
+    fn line_without_digit() {
+        let error = Day01::check("a1b\nxyz\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(part1("a1b\neightwo\n"), 11);
     }
 
     #[test]
     fn test_fancy_input() {
         let input = "sevenine";
-        assert_eq!(part2_v3(&input), 79);
+        assert_eq!(part2_v3(input), 79);
     }
 
     #[test]
     fn test_fancy2_input() {
         let input = "dkmmzhbvq3three6threeq";
-        assert_eq!(part2_v3(&input), 33);
+        assert_eq!(part2_v3(input), 33);
     }
 
     #[test]
     fn test_fancy3_input() {
         let input = "sbzvkxclj33zgfrqrv";
-        assert_eq!(part2_v3(&input), 33);
+        assert_eq!(part2_v3(input), 33);
+    }
+
+    #[test]
+    fn variants_read_overlapping_words() {
+        let input = "eightwo\ntwone\nsevenine\nxoneight\n";
+        let expected = 82 + 21 + 79 + 18;
+        assert_eq!(part2_v1(input), expected);
+        assert_eq!(part2_v2(input), expected);
+        assert_eq!(part2_v3(input), expected);
     }
 }
@@ -0,0 +1,149 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
+
+const DAY: u8 = 2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_games(input).map(drop)
+    }
+}
+
+#[derive(Debug)]
+struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+#[derive(Debug, Default)]
+struct Set {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl Game {
+    /// `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue`
+    fn parse(parser: &mut Parser) -> ParseResult<Self> {
+        parser.literal("Game")?;
+        let id = parser.integer()?;
+        parser.literal(":")?;
+        let sets = parser.separated(";", Set::parse)?;
+
+        Ok(Game { id, sets })
+    }
+}
+
+impl Set {
+    fn parse(parser: &mut Parser) -> ParseResult<Self> {
+        let mut set = Set::default();
+        let cubes = parser.separated(",", |parser| {
+            Ok((parser.integer()?, parser.keyword(&["red", "green", "blue"])?))
+        })?;
+        for (count, color) in cubes {
+            match color {
+                "red" => set.red = count,
+                "green" => set.green = count,
+                _ => set.blue = count,
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse::parse(DAY, line, Game::parse)
+    }
+}
+
+fn parse_games(input: &str) -> ParseResult<Vec<Game>> {
+    parse::lines(DAY, input, Game::parse)
+}
+
+fn part1(input: &str) -> u32 {
+    parse_games(input)
+        .unwrap()
+        .into_iter()
+        .map(|game| {
+            let invalid = game
+                .sets
+                .iter()
+                .any(|set| set.red > 12 || set.green > 13 || set.blue > 14);
+            if invalid {
+                0
+            } else {
+                game.id
+            }
+        })
+        .sum()
+}
+
+fn part2(input: &str) -> u32 {
+    parse_games(input)
+        .unwrap()
+        .into_iter()
+        .map(|game| {
+            let maximums = game.sets.iter().fold(Set::default(), |maximums, set| Set {
+                red: maximums.red.max(set.red),
+                green: maximums.green.max(set.green),
+                blue: maximums.blue.max(set.blue),
+            });
+            maximums.red * maximums.green * maximums.blue
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 8;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 2286;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_game() {
+        let game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red".parse::<Game>().unwrap();
+        assert_eq!(game.id, 3);
+        assert_eq!((game.sets[0].red, game.sets[0].green, game.sets[0].blue), (20, 8, 6));
+        assert_eq!(game.sets[1].green, 0);
+
+        let error = "Game 4: 1 red, 2 purple".parse::<Game>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 18));
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use crate::{
+    grid::{Grid, Position},
+    parse::{self, ParseResult},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        Schematic::new(input).map(drop)
+    }
+}
+
+struct Schematic(Grid<Char>);
+
+#[derive(Debug, Clone, Copy)]
+enum Char {
+    Symbol(char),
+    Digit(char),
+    None,
+}
+
+impl TryFrom<char> for Char {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if "!@#$%^&*()_+-=<>?:\"{}[];',/".contains(c) {
+            Ok(Char::Symbol(c))
+        } else if c.is_ascii_digit() {
+            Ok(Char::Digit(c))
+        } else if c == '.' {
+            Ok(Char::None)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl Schematic {
+    fn new(input: &str) -> ParseResult<Self> {
+        let grid = parse::grid(DAY, input, "a digit, `.` or a symbol", |c| Char::try_from(c).ok())?;
+        Ok(Self(grid))
+    }
+
+    fn get_char(&self, position: Position) -> Char {
+        self.0.get(position).copied().unwrap_or(Char::None)
+    }
+
+    fn get_part_number_at(
+        &self,
+        (y, x): Position,
+        read_positions: &mut HashSet<Position>,
+    ) -> Option<u32> {
+        match self.get_char((y, x)) {
+            Char::Symbol(_) => return None,
+            Char::Digit(_) => (),
+            Char::None => return None,
+        }
+
+        let mut start_x = x;
+        while start_x > 0 && matches!(self.get_char((y, start_x - 1)), Char::Digit(_)) {
+            start_x -= 1;
+        }
+
+        let mut digits = Vec::<char>::new();
+        let mut tmp_x = start_x;
+        while let Char::Digit(digit) = self.get_char((y, tmp_x)) {
+            read_positions.insert((y, tmp_x));
+            digits.push(digit);
+            tmp_x += 1;
+        }
+        Some(digits.iter().collect::<String>().parse().unwrap())
+    }
+
+    /// Part numbers adjacent to the symbol at `position` that have not been read yet.
+    fn read_adjacent_parts(
+        &self,
+        position: Position,
+        read_positions: &mut HashSet<Position>,
+    ) -> Vec<u32> {
+        self.0
+            .neighbours8(position)
+            .filter_map(|neighbour| {
+                if read_positions.contains(&neighbour) {
+                    return None;
+                }
+                self.get_part_number_at(neighbour, read_positions)
+            })
+            .collect_vec()
+    }
+
+    fn read_valid_parts(&self) -> Vec<u32> {
+        let mut read_positions = HashSet::<Position>::new();
+
+        self.0
+            .find_all(|c| matches!(c, Char::Symbol(_)))
+            .flat_map(|position| self.read_adjacent_parts(position, &mut read_positions))
+            .collect_vec()
+    }
+
+    fn read_valid_gears(&self) -> Vec<(u32, u32)> {
+        let mut read_positions = HashSet::<Position>::new();
+
+        self.0
+            .find_all(|c| matches!(c, Char::Symbol('*')))
+            .filter_map(|position| {
+                let current_gears = self.read_adjacent_parts(position, &mut read_positions);
+                current_gears.into_iter().collect_tuple()
+            })
+            .collect_vec()
+    }
+}
+
+fn part1(input: &str) -> u32 {
+    Schematic::new(input).unwrap().read_valid_parts().iter().sum()
+}
+
+fn part2(input: &str) -> u32 {
+    Schematic::new(input)
+        .unwrap()
+        .read_valid_gears()
+        .iter()
+        .map(|(a, b)| a * b)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 4361;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 467835;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn unexpected_char() {
+        let Err(error) = Schematic::new("467..114..\n...*..x...") else {
+            panic!("`x` is not a symbol");
+        };
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+}
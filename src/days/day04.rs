@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 4;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_cards(input).map(drop)
+    }
+}
+
+#[derive(Debug)]
+struct Card {
+    #[allow(dead_code)]
+    id: u32,
+    instances: u32,
+    winning: HashSet<u32>,
+    our: HashSet<u32>,
+}
+
+impl Card {
+    /// `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`
+    fn parse(parser: &mut Parser) -> ParseResult<Self> {
+        parser.literal("Card")?;
+        let id = parser.integer()?;
+        parser.literal(":")?;
+        let winning = parser.integers()?.into_iter().collect();
+        parser.literal("|")?;
+        let our = parser.integers()?.into_iter().collect();
+
+        Ok(Card { id, instances: 1, winning, our })
+    }
+}
+
+fn parse_cards(input: &str) -> ParseResult<Vec<Card>> {
+    parse::lines(DAY, input, Card::parse)
+}
+
+fn part1(input: &str) -> u32 {
+    parse_cards(input)
+        .unwrap()
+        .iter()
+        .map(|card| card.our.intersection(&card.winning).collect_vec().len() as u32)
+        .map(|count| if count == 0 { 0 } else { 2_u32.pow(count - 1) })
+        .sum()
+}
+
+fn part2(input: &str) -> u32 {
+    let mut cards = parse_cards(input).unwrap();
+
+    for j in 0..cards.len() {
+        let card = &cards[j];
+        let count_of_winning = card.our.intersection(&card.winning).collect_vec().len() as u32;
+        for _ in 0..card.instances {
+            if count_of_winning != 0 {
+                for i in 1..=count_of_winning {
+                    // let x = cards.get_mut((card.id - 1 + i) as usize).unwrap();
+                    let index = j + i as usize;
+                    let x = &mut cards[index];
+                    x.instances += 1;
+                    // println!("add 1 to {}, it has now {}", index + 1, x.instances);
+                }
+            }
+        }
+    }
+    // dbg!(&cards);
+
+    cards.iter().map(|card| card.instances).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 13;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 30;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_error_location() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 32 / 17 61";
+        let error = parse::lines(DAY, input, Card::parse).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 15));
+        assert_eq!(error.expected, "`|`");
+    }
+}
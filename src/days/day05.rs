@@ -0,0 +1,262 @@
+use std::{ops::Range, str::FromStr};
+
+use crate::{
+    interval::{IntervalSet, PiecewiseShift},
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 5;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn render(input: &str, part: u8) -> Option<String> {
+        Some(render_almanac(input, part))
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    /// The seed-to-soil map, then soil-to-fertilizer and so on up to
+    /// humidity-to-location, in the order of the input.
+    maps: Vec<PiecewiseShift<i64>>,
+}
+
+impl Almanac {
+    /// All the maps composed into one that takes a seed to its location.
+    fn seed_to_location(&self) -> PiecewiseShift<i64> {
+        self.maps.iter().fold(PiecewiseShift::identity(), |composed, map| composed.then(map))
+    }
+
+    fn lowest_location(&self, seeds: &IntervalSet<i64>) -> i64 {
+        self.seed_to_location().image(seeds).min().expect("parse rejects an almanac without seeds")
+    }
+
+    /// Every seed, planted or not, that ends up at one of `locations`.
+    pub fn seeds_landing_in(&self, locations: &IntervalSet<i64>) -> IntervalSet<i64> {
+        self.seed_to_location().preimage(locations)
+    }
+
+    /// The seeds of part 1, each a seed number of its own.
+    pub fn seed_numbers(&self) -> IntervalSet<i64> {
+        self.seeds.iter().map(|seed| *seed..seed + 1).collect()
+    }
+
+    /// The seeds of part 2, which come in pairs of the first seed of a range
+    /// and its length.
+    pub fn seed_ranges(&self) -> IntervalSet<i64> {
+        self.seeds.iter().tuples().map(|(start, length)| *start..start + length).collect()
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+/// One row per range of seeds that the composed map shifts, with the
+/// locations they end up at. Seeds outside these ranges keep their number.
+fn breakpoint_table(seed_to_location: &PiecewiseShift<i64>) -> String {
+    let header = format!("{:>25} {:>25} {:>12}", "seeds", "locations", "shift");
+    let rows = seed_to_location.pieces().iter().map(|(seeds, shift)| {
+        let seeds_column = format!("{}..{}", seeds.start, seeds.end);
+        let locations_column = format!("{}..{}", seeds.start + shift, seeds.end + shift);
+        format!("{seeds_column:>25} {locations_column:>25} {shift:>+12}")
+    });
+
+    [header].into_iter().chain(rows).join("\n")
+}
+
+/// The breakpoints of the composed almanac, then the planted seeds that
+/// reach the lowest location.
+fn render_almanac(input: &str, part: u8) -> String {
+    let almanac = parse(input).unwrap();
+    let seeds = match part {
+        1 => almanac.seed_numbers(),
+        _ => almanac.seed_ranges(),
+    };
+
+    let lowest = almanac.lowest_location(&seeds);
+    let closest = almanac.seeds_landing_in(&(lowest..lowest + 1).into());
+    let planted = closest
+        .intersection(&seeds)
+        .intervals()
+        .iter()
+        .map(|seeds| format!("{seeds:?}"))
+        .join(", ");
+
+    format!(
+        "{}\n\nplanted seeds reaching location {lowest}: {planted}",
+        breakpoint_table(&almanac.seed_to_location())
+    )
+}
+
+/// The seeds that end up at one of `locations`, then those of them that are
+/// planted in each part.
+pub fn render_seeds_landing_in(almanac: &Almanac, locations: Range<i64>) -> String {
+    let seeds = almanac.seeds_landing_in(&locations.clone().into());
+    let format = |seeds: &IntervalSet<i64>| {
+        if seeds.is_empty() {
+            "none".to_string()
+        } else {
+            seeds.intervals().iter().map(|seeds| format!("{seeds:?}")).join(", ")
+        }
+    };
+
+    let planted = [almanac.seed_numbers(), almanac.seed_ranges()]
+        .map(|planted| format(&seeds.intersection(&planted)));
+    format!(
+        "seeds landing in {locations:?}: {}\nplanted in part 1: {}\nplanted in part 2: {}",
+        format(&seeds),
+        planted[0],
+        planted[1]
+    )
+}
+
+fn parse(input: &str) -> ParseResult<Almanac> {
+    parse::parse(DAY, input, |parser| {
+        let seeds = parse_seeds(parser)?;
+        let maps = parser.blocks(parse_map)?;
+
+        Ok(Almanac { seeds, maps })
+    })
+}
+
+/// `seeds:` followed by pairs of the first seed of a range and its length,
+/// which part 1 reads as plain seed numbers. A range must not be empty.
+fn parse_seeds(parser: &mut Parser) -> ParseResult<Vec<i64>> {
+    parser.literal("seeds:")?;
+
+    let mut seeds = vec![];
+    loop {
+        let start: i64 = parser.integer().map_err(|_| parser.error("a seed number"))?;
+        parser.skip_spaces();
+        let position = parser.position();
+        let length: i64 = parser.integer().map_err(|_| parser.error("a seed range length"))?;
+        if length == 0 {
+            return Err(parser.error_at(position, "a seed range length above 0"));
+        }
+        seeds.extend([start, length]);
+
+        parser.skip_spaces();
+        if !parser.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            return Ok(seeds);
+        }
+    }
+}
+
+/// `seed-to-soil map:` followed by `destination source length` lines, whose
+/// source ranges must not overlap.
+fn parse_map(parser: &mut Parser) -> ParseResult<PiecewiseShift<i64>> {
+    parser.take_while(|c| c.is_ascii_alphabetic() || c == '-');
+    parser.literal("map:")?;
+    parser.end_of_line()?;
+
+    let mut sources: Vec<Range<i64>> = vec![];
+    let mut map = PiecewiseShift::identity();
+    parser.lines(|parser| {
+        let destination_start = parser.integer::<i64>()?;
+        parser.skip_spaces();
+        let position = parser.position();
+        let source_start = parser.integer::<i64>()?;
+        let length = parser.integer::<i64>()?;
+
+        let source = source_start..source_start + length;
+        if sources.iter().any(|other| other.start < source.end && source.start < other.end) {
+            return Err(parser.error_at(position, "a source range that overlaps no other"));
+        }
+        map.insert(source.clone(), destination_start - source_start);
+        sources.push(source);
+        Ok(())
+    })?;
+
+    Ok(map)
+}
+
+fn part1(input: &str) -> i64 {
+    let almanac = parse(input).unwrap();
+    almanac.lowest_location(&almanac.seed_numbers())
+}
+
+fn part2(input: &str) -> i64 {
+    let almanac = parse(input).unwrap();
+    almanac.lowest_location(&almanac.seed_ranges())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 35;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn reverse_query() {
+        let almanac = parse(&get_test_input()).unwrap();
+        let seeds = almanac.seeds_landing_in(&(46..47).into());
+        assert!(seeds.contains(82));
+        for seed in seeds.intervals().iter().flat_map(|seeds| seeds.clone()) {
+            assert_eq!(almanac.maps.iter().fold(seed, |value, map| map.apply(value)), 46);
+        }
+    }
+
+    #[test]
+    fn render_reverse_query() {
+        let almanac = get_test_input().parse::<Almanac>().unwrap();
+        let rendered = render_seeds_landing_in(&almanac, 46..47);
+        assert!(rendered.contains("planted in part 1: none"));
+        assert!(rendered.contains("planted in part 2: 82..83"));
+    }
+
+    #[test]
+    fn overlapping_sources() {
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 50 49\n";
+        let error = Day05::check(input).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 4));
+        assert!(Day05::check("seeds:\n\nseed-to-soil map:\n50 98 2\n").is_err());
+    }
+
+    #[test]
+    fn seeds_come_in_pairs() {
+        let maps = "\n\nseed-to-soil map:\n50 98 2\n";
+        let error = Day05::check(&format!("seeds: 79 14 55{maps}")).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 16));
+        let error = Day05::check(&format!("seeds: 5 0{maps}")).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 10));
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 46;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    parse::{self, ParseResult},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 6;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_races(input).map(drop)
+    }
+}
+
+fn calculate_num_of_ways_to_win(end_time: u64, record_distance: u64) -> u64 {
+    let square_root_delta = ((end_time.pow(2) - 4 * record_distance) as f32).sqrt();
+
+    let left = ((-(end_time as f32) + square_root_delta) / -2_f32).ceil() as u64;
+    let right = ((-(end_time as f32) - square_root_delta) / -2_f32).floor() as u64;
+
+    let result = (right).abs_diff(left) + 1;
+
+    if square_root_delta.fract() == 0.0 {
+        result - 2
+    } else {
+        result
+    }
+}
+
+/// The `Time:` and `Distance:` lines, with the numbers as they are written.
+fn parse_races(input: &str) -> ParseResult<(Vec<u64>, Vec<u64>)> {
+    parse::parse(DAY, input, |parser| {
+        parser.literal("Time:")?;
+        let times = parser.integers()?;
+        parser.end_of_line()?;
+        parser.literal("Distance:")?;
+        let distances = parser.integers()?;
+
+        Ok((times, distances))
+    })
+}
+
+/// The number the digits of `numbers` make when the spaces between them are
+/// ignored.
+fn join_digits(numbers: &[u64]) -> u64 {
+    numbers.iter().join("").parse().unwrap()
+}
+
+fn part1(input: &str) -> u64 {
+    let (times, distances) = parse_races(input).unwrap();
+
+    times
+        .iter()
+        .zip(distances)
+        .map(|(end_time, record_distance)| calculate_num_of_ways_to_win(*end_time, record_distance))
+        .product()
+}
+
+fn part2(input: &str) -> u64 {
+    let (times, distances) = parse_races(input).unwrap();
+
+    calculate_num_of_ways_to_win(join_digits(&times), join_digits(&distances))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 288;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 71503;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
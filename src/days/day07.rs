@@ -1,15 +1,28 @@
-use aoc2023::{read_input, InputType};
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
 use counter::Counter;
 use itertools::Itertools;
-use timed::timed;
 
 const DAY: u8 = 7;
 
-fn main() {
-    let real_input = read_input(DAY, InputType::Real).unwrap();
+pub struct Day07;
 
-    println!("Part1: {}", part1(&real_input));
-    println!("Part2: {}", part2(&real_input));
+impl Solution for Day07 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_hands(input).map(drop)
+    }
 }
 
 #[derive(Debug, PartialEq, Hash, Eq)]
@@ -116,14 +129,14 @@ impl TryFrom<&Vec<Card>> for HandType {
         let counter = cards.iter().collect::<Counter<_>>();
         let values = counter.values().sorted().collect_vec();
 
-        match values.as_slice() {
-            &[5] => Ok(HandType::FiveOfKind),
-            &[1, 4] => Ok(HandType::FourOfKind),
-            &[1, 1, 3] => Ok(HandType::ThreeOfKind),
-            &[2, 3] => Ok(HandType::FullHouse),
-            &[1, 2, 2] => Ok(HandType::TwoPair),
-            &[1, 1, 1, 2] => Ok(HandType::OnePair),
-            &[1, 1, 1, 1, 1] => Ok(HandType::HighCard),
+        match *values.as_slice() {
+            [5] => Ok(HandType::FiveOfKind),
+            [1, 4] => Ok(HandType::FourOfKind),
+            [1, 1, 3] => Ok(HandType::ThreeOfKind),
+            [2, 3] => Ok(HandType::FullHouse),
+            [1, 2, 2] => Ok(HandType::TwoPair),
+            [1, 1, 1, 2] => Ok(HandType::OnePair),
+            [1, 1, 1, 1, 1] => Ok(HandType::HighCard),
             _ => Err(()),
         }
     }
@@ -135,26 +148,24 @@ impl PartialOrd for HandType {
     }
 }
 
-impl TryFrom<&str> for Hand {
-    type Error = ();
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let value = value.trim();
-        if value.len() != 5 {
-            return Err(());
-        }
-
-        let cards = value
-            .chars()
-            .map(|char| char.try_into().unwrap())
-            .collect_vec();
-
+impl Hand {
+    /// Five cards, e.g. `KTJJT`.
+    fn parse(parser: &mut Parser) -> ParseResult<Self> {
+        parser.skip_spaces();
+        let cards = (0..5)
+            .map(|_| parser.character("a card", |c| Card::try_from(c).ok()))
+            .collect::<ParseResult<Vec<_>>>()?;
         let hand_type = (&cards).try_into().unwrap();
 
         Ok(Hand { cards, hand_type })
     }
 }
 
+/// A hand and its bid per line.
+fn parse_hands(input: &str) -> ParseResult<Vec<(Hand, u64)>> {
+    parse::lines(DAY, input, |parser| Ok((Hand::parse(parser)?, parser.integer()?)))
+}
+
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match self.hand_type.partial_cmp(&other.hand_type) {
@@ -174,7 +185,7 @@ impl PartialOrd for Hand {
                             None => unreachable!(),
                         }
                     }
-                    return Some(std::cmp::Ordering::Equal);
+                    Some(std::cmp::Ordering::Equal)
                 }
             },
             None => unreachable!(),
@@ -182,16 +193,10 @@ impl PartialOrd for Hand {
     }
 }
 
-#[timed]
-fn part1(input: &str) -> u64 {
-    input
-        .lines()
-        .map(|line| {
-            let (hand_part, bid_part) = line.trim().split(" ").collect_tuple().unwrap();
-            let hand = Hand::try_from(hand_part).unwrap();
-            let bid = bid_part.parse::<u64>().unwrap();
-            (hand, bid)
-        })
+pub(super) fn part1(input: &str) -> u64 {
+    parse_hands(input)
+        .unwrap()
+        .into_iter()
         .sorted_by(|(hand1, _), (hand2, _)| hand1.partial_cmp(hand2).unwrap())
         .enumerate()
         .map(|(index, (hand, bid))| {
@@ -202,14 +207,15 @@ fn part1(input: &str) -> u64 {
         .sum()
 }
 
-#[timed]
+/// The joker rules live in [`super::day07b`], whose cards rank `J` lowest.
 fn part2(input: &str) -> u64 {
-    todo!()
+    super::day07b::part2(input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{read_input, InputType};
 
     fn get_test_input() -> String {
         read_input(DAY, InputType::Test).unwrap()
@@ -222,24 +228,10 @@ mod tests {
         assert_eq!(result, expected);
     }
 
-    #[test]
-    fn part1_wj() {
-        let expected = 251927063;
-        let result = part1(&read_input(DAY, InputType::Other("WJ".to_string())).unwrap());
-        assert_eq!(result, expected);
-    }
-
     #[test]
     fn part2_test() {
         let expected = 5905;
         let result = part2(&get_test_input());
         assert_eq!(result, expected);
     }
-
-    #[test]
-    fn part2_wj() {
-        let expected = 255632664;
-        let result = part2(&read_input(DAY, InputType::Other("WJ".to_string())).unwrap());
-        assert_eq!(result, expected);
-    }
 }
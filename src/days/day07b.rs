@@ -1,16 +1,30 @@
-use aoc2023::{read_input, InputType};
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
 use counter::Counter;
 use itertools::Itertools;
 use strum::*;
 use strum_macros::EnumIter;
-use timed::timed;
 
 const DAY: u8 = 7;
 
-fn main() {
-    let real_input = read_input(DAY, InputType::Real).unwrap();
+pub struct Day07b;
 
-    println!("Part2: {}", part2(&real_input));
+impl Solution for Day07b {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        super::day07::part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_hands(input).map(drop)
+    }
 }
 
 #[derive(Debug, PartialEq, Hash, Eq, Clone, PartialOrd, Ord, EnumIter)]
@@ -106,22 +120,24 @@ fn get_hand_type(cards: &[Card]) -> HandType {
     }
 }
 
-impl TryFrom<&str> for Hand {
-    type Error = ();
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let value = value.trim();
-        if value.len() != 5 {
-            return Err(());
-        }
-
-        let cards = value.chars().flat_map(|char| char.try_into()).collect_vec();
+impl Hand {
+    /// Five cards, e.g. `KTJJT`.
+    fn parse(parser: &mut Parser) -> ParseResult<Self> {
+        parser.skip_spaces();
+        let cards = (0..5)
+            .map(|_| parser.character("a card", |c| Card::try_from(c).ok()))
+            .collect::<ParseResult<Vec<_>>>()?;
         let hand_type = cards.as_slice().try_into().unwrap();
 
         Ok(Hand { cards, hand_type })
     }
 }
 
+/// A hand and its bid per line.
+fn parse_hands(input: &str) -> ParseResult<Vec<(Hand, u64)>> {
+    parse::lines(DAY, input, |parser| Ok((Hand::parse(parser)?, parser.integer()?)))
+}
+
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         let type_ordering = self.hand_type.cmp(&other.hand_type);
@@ -142,16 +158,10 @@ impl PartialOrd for Hand {
     }
 }
 
-#[timed]
-fn part2(input: &str) -> u64 {
-    input
-        .lines()
-        .map(|line| {
-            let (hand_part, bid_part) = line.split_whitespace().collect_tuple().unwrap();
-            let hand = Hand::try_from(hand_part).unwrap();
-            let bid = bid_part.parse::<u64>().unwrap();
-            (hand, bid)
-        })
+pub(super) fn part2(input: &str) -> u64 {
+    parse_hands(input)
+        .unwrap()
+        .into_iter()
         .sorted_by(|(hand1, _), (hand2, _)| hand1.partial_cmp(hand2).unwrap())
         .enumerate()
         .map(|(index, (hand, bid))| {
@@ -166,6 +176,7 @@ fn part2(input: &str) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{read_input, InputType};
 
     fn get_test_input() -> String {
         read_input(DAY, InputType::Test).unwrap()
@@ -184,18 +195,4 @@ mod tests {
         let result = part2(&read_input(DAY, InputType::Other("test2".to_string())).unwrap());
         assert_eq!(result, expected);
     }
-
-    #[test]
-    fn part2_real() {
-        let expected = 253907829;
-        let result = part2(&read_input(DAY, InputType::Real).unwrap());
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn part2_wj() {
-        let expected = 255632664;
-        let result = part2(&read_input(DAY, InputType::Other("WJ".to_string())).unwrap());
-        assert_eq!(result, expected);
-    }
 }
@@ -1,42 +1,58 @@
 use std::collections::HashMap;
 
-use aoc2023::{read_input, InputType};
+use crate::{
+    parse::{self, ParseResult},
+    Answer, ParseError, Solution,
+};
 use itertools::Itertools;
 use num::Integer;
-use timed::timed;
 
 const DAY: u8 = 8;
 
-fn main() {
-    let real_input = read_input(DAY, InputType::Real).unwrap();
+pub struct Day08;
 
-    println!("Part1: {}", part1(&real_input));
-    println!("Part2: {}", part2(&real_input));
+impl Solution for Day08 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_network(input).map(drop)
+    }
+}
+
+type Nodes<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+/// The `LR` instructions, then the `AAA = (BBB, CCC)` nodes.
+fn parse_network(input: &str) -> ParseResult<(Vec<char>, Nodes<'_>)> {
+    parse::parse(DAY, input, |parser| {
+        let instructions = parser.word()?.chars().collect_vec();
+        parser.end_of_line()?;
+        parser.skip_whitespace();
+
+        let nodes = parser.lines(|parser| {
+            let from = parser.word()?;
+            parser.literal("=")?;
+            parser.literal("(")?;
+            let to_left = parser.word()?;
+            parser.literal(",")?;
+            let to_right = parser.word()?;
+            parser.literal(")")?;
+            Ok((from, (to_left, to_right)))
+        })?;
+
+        Ok((instructions, nodes.into_iter().collect()))
+    })
 }
 
-#[timed]
 fn part1(input: &str) -> u64 {
-    let (instructions_str, nodes_str) = input.split("\r\n\r\n").collect_tuple().unwrap();
-    let instructions = instructions_str.trim().chars().collect_vec();
-    let nodes = nodes_str
-        .trim()
-        .lines()
-        .map(|line| {
-            let (from, to) = line.trim().split("=").map(|v| v.trim()).collect_tuple().unwrap();
-            let (to_left, to_right) = to
-                .trim_start_matches("(")
-                .trim_end_matches(")")
-                .split(",")
-                .map(|v| v.trim())
-                .collect_tuple()
-                .unwrap();
-
-            (from, (to_left, to_right))
-        })
-        .fold(HashMap::new(), |mut map, (from, to)| {
-            map.insert(from, to);
-            map
-        });
+    let (instructions, nodes) = parse_network(input).unwrap();
 
     let mut steps = 0;
     let mut current_node = "AAA";
@@ -59,34 +75,13 @@ fn part1(input: &str) -> u64 {
     steps
 }
 
-#[timed]
 fn part2(input: &str) -> u64 {
-    let (instructions_str, nodes_str) = input.split("\r\n\r\n").collect_tuple().unwrap();
-    let instructions = instructions_str.trim().chars().collect_vec();
-    let nodes = nodes_str
-        .trim()
-        .lines()
-        .map(|line| {
-            let (from, to) = line.trim().split("=").map(|v| v.trim()).collect_tuple().unwrap();
-            let (to_left, to_right) = to
-                .trim_start_matches("(")
-                .trim_end_matches(")")
-                .split(",")
-                .map(|v| v.trim())
-                .collect_tuple()
-                .unwrap();
-
-            (from, (to_left, to_right))
-        })
-        .fold(HashMap::new(), |mut map, (from, to)| {
-            map.insert(from, to);
-            map
-        });
+    let (instructions, nodes) = parse_network(input).unwrap();
 
     nodes
         .iter()
-        .filter(|(k, v)| k.ends_with("A"))
-        .map(|(k, v)| *k)
+        .filter(|(k, _v)| k.ends_with("A"))
+        .map(|(k, _v)| *k)
         .map(|start| {
             let mut current_node = start;
             let mut steps = 0;
@@ -114,6 +109,7 @@ fn part2(input: &str) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{read_input, InputType};
 
     fn get_test_input() -> String {
         read_input(DAY, InputType::Test).unwrap()
@@ -1,14 +1,27 @@
-use aoc2023::{read_input, InputType};
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
 use itertools::Itertools;
-use timed::timed;
 
 const DAY: u8 = 9;
 
-fn main() {
-    let real_input = read_input(DAY, InputType::Real).unwrap();
+pub struct Day09;
 
-    println!("Part1: {}", part1(&real_input));
-    println!("Part2: {}", part2(&real_input));
+impl Solution for Day09 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_histories(input).map(drop)
+    }
 }
 
 #[derive(Debug)]
@@ -31,7 +44,7 @@ impl NumberSequence {
     }
 
     fn calculate_next(&mut self) {
-        if let None = self.differential {
+        if self.differential.is_none() {
             let next_value = self.numbers.last().unwrap();
             self.numbers.push(*next_value);
             return;
@@ -45,7 +58,7 @@ impl NumberSequence {
     }
 
     fn calculate_previous(&mut self) {
-        if let None = self.differential {
+        if self.differential.is_none() {
             let next_value = self.numbers.first().unwrap();
             self.numbers.insert(0, *next_value);
             return;
@@ -59,32 +72,37 @@ impl NumberSequence {
     }
 }
 
-#[timed]
+/// The history of a value per line, at least one reading each.
+fn parse_histories(input: &str) -> ParseResult<Vec<Vec<i64>>> {
+    parse::lines(DAY, input, |parser| {
+        let first = parser.signed()?;
+        let rest = parser.list(Parser::signed)?;
+        Ok([vec![first], rest].concat())
+    })
+}
+
 fn part1(input: &str) -> i64 {
-    input
-        .trim()
-        .lines()
-        .map(|line| line.trim().split_whitespace().flat_map(|num| num.parse::<i64>()).collect_vec())
+    parse_histories(input)
+        .unwrap()
+        .into_iter()
         .map(|numbers| {
             let mut number_sequence = NumberSequence { numbers, differential: None };
             number_sequence.calculate_differentials_all_the_way_down();
             number_sequence.calculate_next();
-            number_sequence.numbers.last().unwrap().clone()
+            *number_sequence.numbers.last().unwrap()
         })
         .sum()
 }
 
-#[timed]
 fn part2(input: &str) -> i64 {
-    input
-        .trim()
-        .lines()
-        .map(|line| line.trim().split_whitespace().flat_map(|num| num.parse::<i64>()).collect_vec())
+    parse_histories(input)
+        .unwrap()
+        .into_iter()
         .map(|numbers| {
             let mut number_sequence = NumberSequence { numbers, differential: None };
             number_sequence.calculate_differentials_all_the_way_down();
             number_sequence.calculate_previous();
-            number_sequence.numbers.first().unwrap().clone()
+            *number_sequence.numbers.first().unwrap()
         })
         .sum()
 }
@@ -92,6 +110,7 @@ fn part2(input: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{read_input, InputType};
 
     fn get_test_input() -> String {
         read_input(DAY, InputType::Test).unwrap()
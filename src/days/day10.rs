@@ -0,0 +1,304 @@
+use std::collections::HashSet;
+
+use crate::{
+    grid::{Grid, Position},
+    parse::{self, ParseResult},
+    polygon, search, Answer, Direction, ParseError, Point, Solution,
+};
+
+const DAY: u8 = 10;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    fn render(input: &str, part: u8) -> Option<String> {
+        (part == 2).then(|| render_enclosed(input))
+    }
+}
+
+#[derive(Debug)]
+enum Tile {
+    Nothing,
+    /// `S`, a pipe whose exits are only known once its neighbours are parsed.
+    Start,
+    Pipe(Pipe),
+}
+
+impl Tile {
+    fn is_connection(&self, to: Direction) -> bool {
+        match self {
+            Tile::Nothing | Tile::Start => false,
+            Tile::Pipe(pipe) => pipe.exits.contains(&to),
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Nothing),
+            'S' => Ok(Self::Start),
+            _ => value.try_into().map(Self::Pipe),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Pipe {
+    exits: [Direction; 2],
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Pipe { exits: [Direction::Up, Direction::Down] }),
+            '-' => Ok(Pipe { exits: [Direction::Left, Direction::Right] }),
+            'L' => Ok(Pipe { exits: [Direction::Up, Direction::Right] }),
+            'J' => Ok(Pipe { exits: [Direction::Up, Direction::Left] }),
+            '7' => Ok(Pipe { exits: [Direction::Left, Direction::Down] }),
+            'F' => Ok(Pipe { exits: [Direction::Right, Direction::Down] }),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Pipe {
+    fn box_drawing(&self) -> char {
+        let has = |direction| self.exits.contains(&direction);
+        match (has(Direction::Up), has(Direction::Right), has(Direction::Down)) {
+            (true, false, true) => '│',
+            (true, true, false) => '└',
+            (true, false, false) => '┘',
+            (false, true, true) => '┌',
+            (false, false, true) => '┐',
+            _ => '─',
+        }
+    }
+}
+
+fn parse(input: &str) -> ParseResult<(Position, Grid<Tile>)> {
+    let (start, mut grid) = parse::parse(DAY, input, |parser| {
+        let grid = parser.grid("a pipe, `.` or `S`", |c| Tile::try_from(c).ok())?;
+        match grid.find(|tile| matches!(tile, Tile::Start)) {
+            Some(start) => Ok((start, grid)),
+            None => Err(parser.error("a row with the start tile `S`")),
+        }
+    })?;
+
+    let exits = [Direction::Up, Direction::Down, Direction::Left, Direction::Right]
+        .into_iter()
+        .filter(|direction| {
+            grid.step(start, *direction)
+                .is_some_and(|neighbour| grid[neighbour].is_connection(direction.reverse()))
+        })
+        .collect::<Vec<_>>();
+
+    let exits = exits.try_into().map_err(|_| {
+        parse::cell_error(DAY, input, start, "an `S` with exactly two pipes connecting to it")
+    })?;
+    grid[start] = Tile::Pipe(Pipe { exits });
+
+    if let Err(broken) = trace_loop(start, &grid) {
+        let expected = "a pipe that connects back to the loop through `S`";
+        return Err(parse::cell_error(DAY, input, broken, expected));
+    }
+
+    Ok((start, grid))
+}
+
+/// Positions the pipe at `position` connects to.
+fn connected(grid: &Grid<Tile>, position: Position) -> Vec<Position> {
+    match &grid[position] {
+        Tile::Pipe(pipe) => {
+            pipe.exits.iter().filter_map(|exit| grid.step(position, *exit)).collect()
+        }
+        Tile::Nothing | Tile::Start => vec![],
+    }
+}
+
+/// Follows the pipes from `start` until they lead back to it, returning the
+/// positions passed with `start` at both ends. Fails with the position of
+/// the first tile that does not connect back to the tile before it, or of
+/// the pipe that leads off the grid.
+fn trace_loop(start: Position, grid: &Grid<Tile>) -> Result<Vec<Position>, Position> {
+    let mut positions = vec![start];
+    let Tile::Pipe(pipe) = &grid[start] else {
+        return Err(start);
+    };
+    let mut direction = pipe.exits[0];
+
+    loop {
+        let current = *positions.last().unwrap();
+        let next = grid.step(current, direction).ok_or(current)?;
+        let Tile::Pipe(pipe) = &grid[next] else {
+            return Err(next);
+        };
+        let Some(exit) = pipe.exits.iter().position(|exit| *exit == direction.reverse()) else {
+            return Err(next);
+        };
+
+        positions.push(next);
+        if next == start {
+            return Ok(positions);
+        }
+        direction = pipe.exits[1 - exit];
+    }
+}
+
+/// The loop through `start`, which [`parse`] has checked is closed.
+fn loop_positions(start: Position, grid: &Grid<Tile>) -> Vec<Position> {
+    trace_loop(start, grid).expect("the loop is closed")
+}
+
+fn part1(input: &str) -> u64 {
+    let (start, grid) = parse(input).unwrap();
+
+    // The farthest point of the loop is the one the two directions meet at.
+    let distances = search::bfs_distances(start, |position| connected(&grid, *position));
+
+    *distances.values().max().unwrap() as u64
+}
+
+/// The loop as a polygon through the centres of its tiles.
+fn loop_polygon(positions: &[Position]) -> Vec<Point<i64>> {
+    // The last position is the start again.
+    positions[..positions.len() - 1].iter().map(|(y, x)| Point::new(*x as i64, *y as i64)).collect()
+}
+
+fn part2(input: &str) -> u64 {
+    let (start, grid) = parse(input).unwrap();
+    let polygon = loop_polygon(&loop_positions(start, &grid));
+
+    polygon::interior_points(&polygon) as u64
+}
+
+/// The maze with the loop drawn in box-drawing characters and every other
+/// tile marked `I` if the loop encloses it and `O` if it does not.
+fn render_enclosed(input: &str) -> String {
+    let (start, grid) = parse(input).unwrap();
+    let on_loop = loop_positions(start, &grid).into_iter().collect::<HashSet<_>>();
+
+    // Scanning a row from the left, a tile is enclosed once the loop has been
+    // crossed an odd number of times. The loop is crossed by a `|`, and by an
+    // `L` or a `J` that with an `F` or a `7` forms a bend going up and down:
+    // counting the pipes that lead up counts each crossing once.
+    let rows = grid.rows().enumerate().map(|(row, tiles)| {
+        let mut inside = false;
+        tiles
+            .iter()
+            .enumerate()
+            .map(|(column, tile)| match tile {
+                Tile::Pipe(pipe) if on_loop.contains(&(row, column)) => {
+                    inside ^= pipe.exits.contains(&Direction::Up);
+                    pipe.box_drawing()
+                }
+                _ if inside => 'I',
+                _ => 'O',
+            })
+            .collect()
+    });
+
+    Grid::from_rows(rows.collect()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    #[test]
+    fn part1_test1() {
+        let expected = 4;
+        let result = part1(&read_input(DAY, InputType::Other("test1".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part1_test2() {
+        let expected = 4;
+        let result = part1(&read_input(DAY, InputType::Other("test2".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part1_test3() {
+        let expected = 8;
+        let result = part1(&read_input(DAY, InputType::Other("test3".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part1_test4() {
+        let expected = 8;
+        let result = part1(&read_input(DAY, InputType::Other("test4".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test1() {
+        let expected = 1;
+        let result = part2(&read_input(DAY, InputType::Other("test1".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test2() {
+        let expected = 1;
+        let result = part2(&read_input(DAY, InputType::Other("test2".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test5() {
+        let expected = 4;
+        let result = part2(&read_input(DAY, InputType::Other("test5".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test6() {
+        let expected = 4;
+        let result = part2(&read_input(DAY, InputType::Other("test6".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test7() {
+        let expected = 8;
+        let result = part2(&read_input(DAY, InputType::Other("test7".to_owned())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn render_test5() {
+        let input = read_input(DAY, InputType::Other("test5".to_owned())).unwrap();
+        let picture = render_enclosed(&input);
+        let inside = picture.chars().filter(|c| *c == 'I').count();
+        assert_eq!(inside, 4);
+        assert!(picture.lines().next().unwrap().chars().all(|c| c == 'O'));
+    }
+
+    #[test]
+    fn open_loop() {
+        let error = Day10::check("S-7\n|.|\nL-.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+}
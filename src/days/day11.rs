@@ -0,0 +1,186 @@
+use crate::{
+    grid::{Grid, Position},
+    parse::{self, ParseResult},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 11;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input, 1_000_000).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        Universe::new(input).map(drop)
+    }
+}
+
+struct Universe(Grid<Tile>);
+
+impl Universe {
+    fn new(input: &str) -> ParseResult<Self> {
+        let grid = parse::grid(DAY, input, "`.` or `#`", |c| {
+            let object = c.try_into().ok()?;
+            Some(Tile { object, vertical_cost: 1, horizontal_cost: 1 })
+        })?;
+        Ok(Self(grid))
+    }
+
+    fn expand(&mut self, expand_rate: u64) {
+        let is_empty = |tile: &Tile| tile.object == Object::Space;
+
+        for row in 0..self.0.height() {
+            if self.0.row(row).iter().all(is_empty) {
+                self.0
+                    .row_mut(row)
+                    .iter_mut()
+                    .for_each(|tile| tile.vertical_cost *= expand_rate);
+            }
+        }
+
+        for column in 0..self.0.width() {
+            if self.0.column(column).all(is_empty) {
+                for row in 0..self.0.height() {
+                    self.0[(row, column)].horizontal_cost *= expand_rate;
+                }
+            }
+        }
+    }
+
+    fn path_from_to(&self, start: Position, end: Position) -> u64 {
+        let mut counter = 0;
+        let mut current = start;
+
+        while current != end {
+            match current.0 as i64 - end.0 as i64 {
+                x if x > 0 => {
+                    counter += self.0[current].vertical_cost;
+                    current.0 -= 1;
+                }
+                x if x < 0 => {
+                    counter += self.0[current].vertical_cost;
+                    current.0 += 1;
+                }
+                _ => (),
+            }
+
+            match current.1 as i64 - end.1 as i64 {
+                x if x > 0 => {
+                    counter += self.0[current].horizontal_cost;
+                    current.1 -= 1;
+                }
+                x if x < 0 => {
+                    counter += self.0[current].horizontal_cost;
+                    current.1 += 1;
+                }
+                _ => (),
+            }
+        }
+
+        counter
+    }
+
+    fn find_galaxies(&self) -> Vec<Position> {
+        self.0.find_all(|tile| tile.object == Object::Galaxy).collect_vec()
+    }
+}
+
+#[derive(Clone)]
+struct Tile {
+    object: Object,
+    vertical_cost: u64,
+    horizontal_cost: u64,
+}
+
+#[derive(PartialEq, Clone)]
+enum Object {
+    Space,
+    Galaxy,
+}
+
+impl TryFrom<char> for Object {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::Galaxy),
+            '.' => Ok(Self::Space),
+            _ => Err(()),
+        }
+    }
+}
+
+fn part1(input: &str) -> u64 {
+    let mut universe = Universe::new(input).unwrap();
+    universe.expand(2);
+
+    universe
+        .find_galaxies()
+        .iter()
+        .combinations(2)
+        .map(|galaxies| {
+            let g1 = galaxies[0];
+            let g2 = galaxies[1];
+
+            universe.path_from_to(*g1, *g2)
+        })
+        .sum::<u64>()
+}
+
+fn part2(input: &str, expand_rate: usize) -> u64 {
+    let mut universe = Universe::new(input).unwrap();
+    universe.expand(expand_rate.try_into().unwrap());
+
+    universe
+        .find_galaxies()
+        .iter()
+        .combinations(2)
+        .map(|galaxies| {
+            let g1 = galaxies[0];
+            let g2 = galaxies[1];
+
+            universe.path_from_to(*g1, *g2)
+        })
+        .sum::<u64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 374;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test1() {
+        let expected = 1030;
+        let result = part2(&get_test_input(), 10);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test2() {
+        let expected = 8410;
+        let result = part2(&get_test_input(), 100);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+const DAY: u8 = 12;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse(input).map(drop)
+    }
+
+    /// Lists the arrangements of every record of part 1.
+    fn render(input: &str, part: u8) -> Option<String> {
+        (part == 1).then(|| {
+            parse(input)
+                .unwrap()
+                .iter()
+                .map(|(conditions, groups)| {
+                    let arrangements = Arrangements::new(conditions, groups).enumerate();
+                    let record = conditions.iter().collect::<String>();
+                    format!("{record} {groups:?}\n  {}", arrangements.join("\n  "))
+                })
+                .join("\n")
+        })
+    }
+}
+
+/// The conditions of a row of springs and the sizes of its damaged groups per
+/// line, e.g. `???.### 1,1,3`.
+fn parse(input: &str) -> ParseResult<Vec<(Vec<char>, Vec<usize>)>> {
+    parse::lines(DAY, input, |parser| {
+        let condition = |c| "?.#".contains(c).then_some(c);
+        let mut conditions = vec![];
+        parser.skip_spaces();
+        while parser.rest().starts_with(|c: char| !c.is_whitespace()) {
+            conditions.push(parser.character("`.`, `#` or `?`", condition)?);
+        }
+        let groups = parser.separated(",", Parser::integer)?;
+
+        Ok((conditions, groups))
+    })
+}
+
+/// For every state of a left to right scan over a record, the number of ways
+/// to finish it into a valid arrangement.
+///
+/// A state is the position in the conditions, the number of groups already
+/// completed, and the length of the run of damaged springs that ends just
+/// before the position. The table is filled back to front once, after which
+/// counting is a lookup and enumerating only follows states that lead to an
+/// arrangement.
+struct Arrangements<'a> {
+    conditions: &'a [char],
+    groups: &'a [usize],
+    longest_group: usize,
+    ways: Vec<usize>,
+}
+
+impl<'a> Arrangements<'a> {
+    fn new(conditions: &'a [char], groups: &'a [usize]) -> Self {
+        let longest_group = groups.iter().copied().max().unwrap_or(0);
+        let len = (conditions.len() + 1) * (groups.len() + 1) * (longest_group + 1);
+        let mut arrangements = Self { conditions, groups, longest_group, ways: vec![0; len] };
+
+        for group in 0..=groups.len() {
+            for run in 0..=longest_group {
+                let done = (group == groups.len() && run == 0)
+                    || (group + 1 == groups.len() && run == groups[group]);
+                let index = arrangements.index(conditions.len(), group, run);
+                arrangements.ways[index] = done as usize;
+            }
+        }
+
+        for position in (0..conditions.len()).rev() {
+            for group in 0..=groups.len() {
+                for run in 0..=longest_group {
+                    let ways = arrangements
+                        .next_states(position, group, run)
+                        .map(|(_, (group, run))| arrangements.ways_from(position + 1, group, run))
+                        .sum();
+                    let index = arrangements.index(position, group, run);
+                    arrangements.ways[index] = ways;
+                }
+            }
+        }
+
+        arrangements
+    }
+
+    fn index(&self, position: usize, group: usize, run: usize) -> usize {
+        (position * (self.groups.len() + 1) + group) * (self.longest_group + 1) + run
+    }
+
+    fn ways_from(&self, position: usize, group: usize, run: usize) -> usize {
+        self.ways[self.index(position, group, run)]
+    }
+
+    /// The springs that can be at `position`, each with the state it leads to.
+    fn next_states(
+        &self,
+        position: usize,
+        group: usize,
+        run: usize,
+    ) -> impl Iterator<Item = (char, (usize, usize))> {
+        let condition = self.conditions[position];
+        let damaged = (condition != '.' && group < self.groups.len() && run < self.groups[group])
+            .then_some(('#', (group, run + 1)));
+        let operational = match condition != '#' {
+            true if run == 0 => Some(('.', (group, 0))),
+            true if self.groups.get(group) == Some(&run) => Some(('.', (group + 1, 0))),
+            _ => None,
+        };
+
+        damaged.into_iter().chain(operational)
+    }
+
+    fn count(&self) -> usize {
+        self.ways_from(0, 0, 0)
+    }
+
+    /// Every arrangement, with the unknown springs filled in.
+    fn enumerate(&self) -> Vec<String> {
+        let mut arrangements = vec![];
+        let mut springs = String::new();
+        self.enumerate_from(0, 0, 0, &mut springs, &mut arrangements);
+        arrangements
+    }
+
+    fn enumerate_from(
+        &self,
+        position: usize,
+        group: usize,
+        run: usize,
+        springs: &mut String,
+        arrangements: &mut Vec<String>,
+    ) {
+        if self.ways_from(position, group, run) == 0 {
+            return;
+        }
+        if position == self.conditions.len() {
+            arrangements.push(springs.clone());
+            return;
+        }
+
+        for (spring, (group, run)) in self.next_states(position, group, run) {
+            springs.push(spring);
+            self.enumerate_from(position + 1, group, run, springs, arrangements);
+            springs.pop();
+        }
+    }
+}
+
+fn unfold(conditions: &[char], groups: &[usize]) -> (Vec<char>, Vec<usize>) {
+    let conditions = [conditions; 5].join(&'?');
+    let groups = groups.repeat(5);
+
+    (conditions, groups)
+}
+
+fn part1(input: &str) -> usize {
+    parse(input)
+        .unwrap()
+        .iter()
+        .map(|(conditions, groups)| Arrangements::new(conditions, groups).count())
+        .sum()
+}
+
+fn part2(input: &str) -> usize {
+    parse(input)
+        .unwrap()
+        .par_iter()
+        .map(|(conditions, groups)| {
+            let (conditions, groups) = unfold(conditions, groups);
+            Arrangements::new(&conditions, &groups).count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    /// Whether the damaged groups of fully known `springs` have the sizes of
+    /// `groups`.
+    fn is_valid(springs: &str, groups: &[usize]) -> bool {
+        springs
+            .split('.')
+            .filter(|group| !group.is_empty())
+            .map(str::len)
+            .eq(groups.iter().copied())
+    }
+
+    #[test]
+    fn enumerate_arrangements() {
+        let (conditions, groups) = parse(".??..??...?##. 1,1,3").unwrap().remove(0);
+        let arrangements = Arrangements::new(&conditions, &groups);
+
+        assert_eq!(arrangements.count(), 4);
+        assert_eq!(
+            arrangements.enumerate(),
+            [".#...#....###.", ".#....#...###.", "..#..#....###.", "..#...#...###."]
+        );
+        assert!(arrangements.enumerate().iter().all(|springs| is_valid(springs, &groups)));
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 21;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 525152;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test1() {
+        let expected = 1;
+        let result = part2("???.### 1,1,3");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test2() {
+        let expected = 1;
+        let result = part1("? 1");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test3() {
+        let expected = 2;
+        let result = part1("?? 1");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test4() {
+        let expected = 1;
+        let result = part1("?? 2");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test5() {
+        let expected = 3;
+        let result = part1("??? 1");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test6() {
+        let expected = 4;
+        let result = part1("???? 1");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test7() {
+        let expected = 3;
+        let result = part1("???? 2");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test8() {
+        let expected = 3;
+        let result = part1("???? 1,1");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("???.### 1,1,3"), 1);
+        assert_eq!(part1(".??..??...?##. 1,1,3"), 4);
+        assert_eq!(part1(".??..??...?##... 1,1,3"), 4);
+        assert_eq!(part1("?#?#?#?#?#?#?#? 1,3,1,6"), 1);
+        assert_eq!(part1("?#?#?#?#?#?#?#?.. 1,3,1,6"), 1);
+        assert_eq!(part1("?#?#?#?#?#?#?#?... 1,3,1,6"), 1);
+        assert_eq!(part1("????.#...#... 4,1,1"), 1);
+        assert_eq!(part1("????.######..#####. 1,6,5"), 4);
+        assert_eq!(part1("?###???????? 3,2,1"), 10);
+    }
+
+    #[test]
+    fn part2_wj1() {
+        let expected = 1;
+        let result = part2(".?##.?.?#? 3,1");
+        assert_eq!(result, expected);
+    }
+}
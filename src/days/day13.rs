@@ -1,16 +1,29 @@
 use std::fmt::Display;
 
-use aoc2023::{read_input, InputType};
+use crate::{
+    parse::{self, ParseResult},
+    Answer, Grid, ParseError, Solution,
+};
 use itertools::Itertools;
-use timed::timed;
 
 const DAY: u8 = 13;
 
-fn main() {
-    let real_input = read_input(DAY, InputType::Real).unwrap();
+pub struct Day13;
 
-    println!("Part1: {}", part1(&real_input));
-    println!("Part2: {}", part2(&real_input));
+impl Solution for Day13 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        Block::from_input(input).map(drop)
+    }
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -35,35 +48,26 @@ impl TryFrom<char> for Tile {
         match value {
             '.' => Ok(Tile::Ash),
             '#' => Ok(Tile::Rock),
-            _ => unreachable!("WTF?"),
+            _ => Err(()),
         }
     }
 }
 
-struct Block(Vec<Vec<Tile>>);
+struct Block(Grid<Tile>);
 
 impl Block {
-    fn from_input(input: &str) -> Vec<Self> {
-        input
-            .trim()
-            .split("\r\n\r\n")
-            .map(|block| {
-                let parsed_block = block
-                    .trim()
-                    .lines()
-                    .map(|line| line.chars().flat_map(Tile::try_from).collect_vec())
-                    .collect_vec();
-                Self(parsed_block)
-            })
-            .collect_vec()
+    fn from_input(input: &str) -> ParseResult<Vec<Self>> {
+        parse::parse(DAY, input, |parser| {
+            parser.blocks(|parser| Ok(Self(parser.grid("`.` or `#`", |c| c.try_into().ok())?)))
+        })
     }
 
     fn get_height(&self) -> usize {
-        self.0.len()
+        self.0.height()
     }
 
-    fn get_width(&self) -> usize {
-        self.0[0].len()
+    fn get_line(&self, index: usize) -> Option<&[Tile]> {
+        (index < self.get_height()).then(|| self.0.row(index))
     }
 
     fn mirror_line_index(&self, replacement: usize, exclude_line: Option<usize>) -> Option<usize> {
@@ -81,12 +85,8 @@ impl Block {
 
             loop {
                 let line_up_index = line.checked_sub(offset);
-                let line_up = if line_up_index.is_some() {
-                    self.0.get(line_up_index.unwrap())
-                } else {
-                    None
-                };
-                let line_down = self.0.get(line + offset + 1);
+                let line_up = line_up_index.and_then(|index| self.get_line(index));
+                let line_down = self.get_line(line + offset + 1);
 
                 if let (Some(line_up), Some(line_down)) = (line_up, line_down) {
                     let differences = line_up
@@ -131,15 +131,7 @@ impl Block {
     }
 
     fn transposed(&self) -> Self {
-        let transposed = (0..self.get_width())
-            .map(|x| {
-                (0..self.get_height())
-                    .map(move |y| self.0.get(y).unwrap().get(x).unwrap().clone())
-                    .collect_vec()
-            })
-            .collect_vec();
-
-        Self(transposed)
+        Self(self.0.transposed())
     }
 
     fn vertical_mirror_line_index(
@@ -150,18 +142,14 @@ impl Block {
         self.transposed().mirror_line_index(replacement, exclude)
     }
 
+    #[allow(dead_code)]
     fn print(&self) {
-        self.0.iter().for_each(|line| {
-            line.iter().for_each(|tile| print!("{}", tile));
-            println!();
-        });
-        println!();
+        println!("{}", self.0);
     }
 }
 
-#[timed]
 fn part1(input: &str) -> usize {
-    let blocks = Block::from_input(input);
+    let blocks = Block::from_input(input).unwrap();
 
     blocks
         .iter()
@@ -180,9 +168,8 @@ fn part1(input: &str) -> usize {
         .sum()
 }
 
-#[timed]
 fn part2(input: &str) -> usize {
-    let blocks = Block::from_input(input);
+    let blocks = Block::from_input(input).unwrap();
 
     blocks
         .iter()
@@ -222,6 +209,7 @@ fn part2(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{read_input, InputType};
 
     fn get_test_input() -> String {
         read_input(DAY, InputType::Test).unwrap()
@@ -234,13 +222,6 @@ mod tests {
         assert_eq!(result, expected);
     }
 
-    #[test]
-    fn part1_real() {
-        let expected = 36041;
-        let result = part1(&read_input(DAY, InputType::Real).unwrap());
-        assert_eq!(result, expected);
-    }
-
     #[test]
     fn part2_test() {
         let expected = 400;
@@ -0,0 +1,246 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    cycle,
+    parse::{self, ParseResult},
+    Answer, Direction, Grid, ParseError, Solution,
+};
+
+const DAY: u8 = 14;
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        Platform::from_input(input).map(drop)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Tile {
+    Space,
+    RoundRock,
+    SquareRock,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Tile::Space => write!(f, "."),
+            Tile::RoundRock => write!(f, "O"),
+            Tile::SquareRock => write!(f, "#"),
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Space),
+            'O' => Ok(Tile::RoundRock),
+            '#' => Ok(Tile::SquareRock),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Platform(Grid<Tile>);
+
+impl Platform {
+    fn from_input(input: &str) -> ParseResult<Self> {
+        let grid = parse::grid(DAY, input, "`.`, `O` or `#`", |c| c.try_into().ok())?;
+        Ok(Self(grid))
+    }
+
+    fn get_new_position_in_direction(
+        &self,
+        y: usize,
+        x: usize,
+        direction: Direction,
+    ) -> (usize, usize) {
+        let mut new_y = y;
+        let mut new_x = x;
+
+        match direction {
+            Direction::Up => {
+                'outer: for temp_y in (0..y).rev() {
+                    for temp_x in x..=x {
+                        if self.0[(temp_y, temp_x)] == Tile::Space {
+                            new_y = temp_y;
+                            new_x = temp_x;
+                        } else {
+                            break 'outer;
+                        }
+                    }
+                }
+            }
+            Direction::Left => {
+                'outer: for temp_x in (0..x).rev() {
+                    for temp_y in y..=y {
+                        if self.0[(temp_y, temp_x)] == Tile::Space {
+                            new_y = temp_y;
+                            new_x = temp_x;
+                        } else {
+                            break 'outer;
+                        }
+                    }
+                }
+            }
+            Direction::Down => {
+                'outer: for temp_y in (y + 1)..self.0.height() {
+                    for temp_x in x..=x {
+                        if self.0[(temp_y, temp_x)] == Tile::Space {
+                            new_y = temp_y;
+                            new_x = temp_x;
+                        } else {
+                            break 'outer;
+                        }
+                    }
+                }
+            }
+            Direction::Right => {
+                'outer: for temp_x in (x + 1)..self.0.width() {
+                    for temp_y in y..=y {
+                        if self.0[(temp_y, temp_x)] == Tile::Space {
+                            new_y = temp_y;
+                            new_x = temp_x;
+                        } else {
+                            break 'outer;
+                        }
+                    }
+                }
+            }
+        }
+
+        (new_y, new_x)
+    }
+    fn move_rocks_in_direction(&mut self, direction: Direction) -> &mut Self {
+        match direction {
+            Direction::Up => {
+                for y in 0..self.0.height() {
+                    for x in 0..self.0.width() {
+                        if self.0[(y, x)] == Tile::RoundRock {
+                            let (new_y, new_x) =
+                                self.get_new_position_in_direction(y, x, direction);
+                            self.0[(y, x)] = Tile::Space;
+                            self.0[(new_y, new_x)] = Tile::RoundRock;
+                        }
+                    }
+                }
+            }
+            Direction::Left => {
+                for x in 0..self.0.width() {
+                    for y in 0..self.0.height() {
+                        if self.0[(y, x)] == Tile::RoundRock {
+                            let (new_y, new_x) =
+                                self.get_new_position_in_direction(y, x, direction);
+                            self.0[(y, x)] = Tile::Space;
+                            self.0[(new_y, new_x)] = Tile::RoundRock;
+                        }
+                    }
+                }
+            }
+            Direction::Down => {
+                for y in (0..self.0.height()).rev() {
+                    for x in 0..self.0.width() {
+                        if self.0[(y, x)] == Tile::RoundRock {
+                            let (new_y, new_x) =
+                                self.get_new_position_in_direction(y, x, direction);
+                            self.0[(y, x)] = Tile::Space;
+                            self.0[(new_y, new_x)] = Tile::RoundRock;
+                        }
+                    }
+                }
+            }
+            Direction::Right => {
+                for x in (0..self.0.width()).rev() {
+                    for y in 0..self.0.height() {
+                        if self.0[(y, x)] == Tile::RoundRock {
+                            let (new_y, new_x) =
+                                self.get_new_position_in_direction(y, x, direction);
+                            self.0[(y, x)] = Tile::Space;
+                            self.0[(new_y, new_x)] = Tile::RoundRock;
+                        }
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Tilts the platform north, west, south and east in turn.
+    fn spin_cycle(&self) -> Self {
+        let mut platform = self.clone();
+        platform
+            .move_rocks_in_direction(Direction::Up)
+            .move_rocks_in_direction(Direction::Left)
+            .move_rocks_in_direction(Direction::Down)
+            .move_rocks_in_direction(Direction::Right);
+        platform
+    }
+
+    fn calculate_north_load(&self) -> usize {
+        let mut load = 0;
+        for y in 0..self.0.height() {
+            for x in 0..self.0.width() {
+                if self.0[(y, x)] == Tile::RoundRock {
+                    load += self.0.height() - y;
+                }
+            }
+        }
+
+        load
+    }
+}
+
+fn part1(input: &str) -> usize {
+    Platform::from_input(input)
+        .unwrap()
+        .move_rocks_in_direction(Direction::Up)
+        .calculate_north_load()
+}
+
+fn part2(input: &str) -> usize {
+    let platform = Platform::from_input(input).unwrap();
+
+    cycle::nth(platform, Platform::spin_cycle, SPIN_CYCLES).calculate_north_load()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 136;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 64;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
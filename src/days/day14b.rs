@@ -0,0 +1,257 @@
+use std::fmt::Display;
+
+use crate::{
+    cycle,
+    parse::{self, ParseResult},
+    Answer, Direction, Grid, ParseError, Solution,
+};
+
+const DAY: u8 = 14;
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+pub struct Day14b;
+
+impl Solution for Day14b {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        Platform::from_input(input).map(drop)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Tile {
+    Space,
+    RoundRock,
+    SquareRock,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Space),
+            'O' => Ok(Tile::RoundRock),
+            '#' => Ok(Tile::SquareRock),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Space => f.write_str("."),
+            Tile::RoundRock => f.write_str("O"),
+            Tile::SquareRock => f.write_str("#"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Platform(Grid<Tile>);
+
+impl Platform {
+    fn from_input(input: &str) -> ParseResult<Self> {
+        let grid = parse::grid(DAY, input, "`.`, `O` or `#`", |c| c.try_into().ok())?;
+        Ok(Self(grid))
+    }
+
+    fn move_rocks_in_direction(&mut self, direction: Direction) -> &mut Self {
+        match direction {
+            Direction::Up => {
+                for x in 0..self.0.width() {
+                    let mut last_space = None;
+                    for y in 0..self.0.height() {
+                        match self.0[(y, x)] {
+                            Tile::Space => {
+                                let beginning = y == 0;
+                                let there_is_previous = y.checked_sub(1).is_some();
+                                let previous_was_rock = there_is_previous
+                                    && (self.0[(y - 1, x)] == Tile::SquareRock
+                                        || self.0[(y - 1, x)] == Tile::RoundRock);
+                                if beginning || previous_was_rock {
+                                    last_space = Some(y)
+                                }
+                            }
+                            Tile::RoundRock => {
+                                if let Some(new_y) = last_space {
+                                    self.0[(y, x)] = Tile::Space;
+                                    self.0[(new_y, x)] = Tile::RoundRock;
+                                    last_space = Some(new_y + 1);
+                                }
+                            }
+                            Tile::SquareRock => {
+                                last_space = None;
+                            }
+                        }
+                    }
+                }
+            }
+
+            Direction::Left => {
+                for y in 0..self.0.height() {
+                    let mut last_space = None;
+                    for x in 0..self.0.width() {
+                        match self.0[(y, x)] {
+                            Tile::Space => {
+                                let beginning = x == 0;
+                                let there_is_previous = x.checked_sub(1).is_some();
+                                let previous_was_rock = there_is_previous
+                                    && (self.0[(y, x - 1)] == Tile::SquareRock
+                                        || self.0[(y, x - 1)] == Tile::RoundRock);
+                                if beginning || previous_was_rock {
+                                    last_space = Some(x)
+                                }
+                            }
+                            Tile::RoundRock => {
+                                if let Some(new_x) = last_space {
+                                    self.0[(y, x)] = Tile::Space;
+                                    self.0[(y, new_x)] = Tile::RoundRock;
+                                    last_space = Some(new_x + 1);
+                                }
+                            }
+                            Tile::SquareRock => {
+                                last_space = None;
+                            }
+                        }
+                    }
+                }
+            }
+
+            Direction::Down => {
+                for x in 0..self.0.width() {
+                    let mut last_space = None;
+                    // for y in (self.0.height() - 1)..=0 {
+                    for y in (0..self.0.height()).rev() {
+                        match self.0[(y, x)] {
+                            Tile::Space => {
+                                let beginning = y == self.0.height() - 1;
+                                let there_is_previous = y + 1 < self.0.height();
+                                let previous_was_rock = there_is_previous
+                                    && (self.0[(y + 1, x)] == Tile::SquareRock
+                                        || self.0[(y + 1, x)] == Tile::RoundRock);
+                                if beginning || previous_was_rock {
+                                    last_space = Some(y)
+                                }
+                            }
+                            Tile::RoundRock => {
+                                if let Some(new_y) = last_space {
+                                    self.0[(y, x)] = Tile::Space;
+                                    self.0[(new_y, x)] = Tile::RoundRock;
+                                    last_space = Some(new_y - 1);
+                                }
+                            }
+                            Tile::SquareRock => {
+                                last_space = None;
+                            }
+                        }
+                    }
+                }
+            }
+            Direction::Right => {
+                for y in 0..self.0.height() {
+                    let mut last_space = None;
+                    // for x in (self.0.width() - 1)..=0 {
+                    for x in (0..self.0.width()).rev() {
+                        match self.0[(y, x)] {
+                            Tile::Space => {
+                                let beginning = x == self.0.width() - 1;
+                                let there_is_previous = x + 1 < self.0.width();
+                                let previous_was_rock = there_is_previous
+                                    && (self.0[(y, x + 1)] == Tile::SquareRock
+                                        || self.0[(y, x + 1)] == Tile::RoundRock);
+                                if beginning || previous_was_rock {
+                                    last_space = Some(x)
+                                }
+                            }
+                            Tile::RoundRock => {
+                                if let Some(new_x) = last_space {
+                                    self.0[(y, x)] = Tile::Space;
+                                    self.0[(y, new_x)] = Tile::RoundRock;
+                                    last_space = Some(new_x - 1);
+                                }
+                            }
+                            Tile::SquareRock => {
+                                last_space = None;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        // self.print_grid();
+
+        self
+    }
+
+    /// Tilts the platform north, west, south and east in turn.
+    fn spin_cycle(&self) -> Self {
+        let mut platform = self.clone();
+        platform
+            .move_rocks_in_direction(Direction::Up)
+            .move_rocks_in_direction(Direction::Left)
+            .move_rocks_in_direction(Direction::Down)
+            .move_rocks_in_direction(Direction::Right);
+        platform
+    }
+
+    fn calculate_north_load(&self) -> usize {
+        let mut load = 0;
+        for y in 0..self.0.height() {
+            for x in 0..self.0.width() {
+                if self.0[(y, x)] == Tile::RoundRock {
+                    load += self.0.height() - y;
+                }
+            }
+        }
+
+        load
+    }
+}
+
+fn part1(input: &str) -> usize {
+    Platform::from_input(input)
+        .unwrap()
+        .move_rocks_in_direction(Direction::Up)
+        .calculate_north_load()
+}
+
+fn part2(input: &str) -> usize {
+    let platform = Platform::from_input(input).unwrap();
+
+    cycle::nth(platform, Platform::spin_cycle, SPIN_CYCLES).calculate_north_load()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 136;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 64;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
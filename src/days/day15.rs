@@ -0,0 +1,120 @@
+use crate::{
+    parse::{self, ParseResult},
+    Answer, ParseError, Solution,
+};
+
+const DAY: u8 = 15;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_steps(input).map(drop)
+    }
+}
+
+fn get_hash(sequence: &str) -> usize {
+    sequence
+        .trim()
+        .chars()
+        .fold(0, |acc, c| (((c as u8) as usize + acc) * 17) % 256)
+}
+
+fn part1(input: &str) -> usize {
+    input.trim().split(',').map(get_hash).sum()
+}
+
+/// A step of the initialization sequence: a lens label followed by `-` to
+/// remove the lens, or by `=` and a focal length to put it in its box.
+enum Step<'a> {
+    Remove(&'a str),
+    Insert(&'a str, usize),
+}
+
+impl<'a> Step<'a> {
+    fn label(&self) -> &'a str {
+        match self {
+            Step::Remove(label) | Step::Insert(label, _) => label,
+        }
+    }
+}
+
+fn parse_steps(input: &str) -> ParseResult<Vec<Step<'_>>> {
+    parse::parse(DAY, input, |parser| {
+        parser.separated(",", |parser| {
+            let label = parser.word()?;
+            match parser.one_of("-=")? {
+                '-' => Ok(Step::Remove(label)),
+                _ => Ok(Step::Insert(label, parser.integer()?)),
+            }
+        })
+    })
+}
+
+fn part2(input: &str) -> usize {
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![vec![]; 256];
+
+    parse_steps(input).unwrap().into_iter().for_each(|step| {
+        let lens_name = step.label();
+        let box_index = get_hash(lens_name);
+        let r#box = boxes.get_mut(box_index).unwrap();
+        let position = r#box
+            .iter()
+            .position(|(lens_in_box_name, _)| *lens_in_box_name == lens_name);
+
+        match (step, position) {
+            (Step::Remove(_), Some(p)) => {
+                r#box.remove(p);
+            }
+            (Step::Remove(_), None) => (),
+            (Step::Insert(_, power), Some(p)) => r#box[p] = (lens_name, power),
+            (Step::Insert(_, power), None) => r#box.push((lens_name, power)),
+        }
+    });
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(box_index, r#box)| {
+            r#box
+                .iter()
+                .enumerate()
+                .map(|(index_in_box, (_, power))| (box_index + 1) * (index_in_box + 1) * power)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 1320;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 145;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    geometry::Mirror,
+    grid::{Grid, Position},
+    parse::{self, ParseResult},
+    Answer, Direction, ParseError, Solution,
+};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+const DAY: u8 = 16;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        LaserGrid::new_from_input(input).map(drop)
+    }
+}
+
+struct LaserGrid {
+    grid: Grid<char>,
+}
+
+impl LaserGrid {
+    fn new_from_input(input: &str) -> ParseResult<Self> {
+        let tile = |c| "./\\|-".contains(c).then_some(c);
+        let grid = parse::grid(DAY, input, "`.`, a mirror or a splitter", tile)?;
+        Ok(Self { grid })
+    }
+
+    fn get_next_step(
+        &self,
+        position: Position,
+        direction: Direction,
+    ) -> Vec<(Position, Direction)> {
+        match self.grid[position] {
+            '.' => [(position, direction)].to_vec(),
+            '/' => [(position, direction.reflect(Mirror::Slash))].to_vec(),
+            '\\' => [(position, direction.reflect(Mirror::Backslash))].to_vec(),
+            '|' if direction.is_vertical() => [(position, direction)].to_vec(),
+            '|' => [(position, Direction::Down), (position, Direction::Up)].to_vec(),
+            '-' if direction.is_vertical() => {
+                [(position, Direction::Right), (position, Direction::Left)].to_vec()
+            }
+            '-' => [(position, direction)].to_vec(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn calculate_energized_positions_after_shot_from(
+        &self,
+        start_position: Position,
+        start_direction: Direction,
+    ) -> HashSet<(Position, Direction)> {
+        let mut visited_positions = HashSet::with_capacity(15000);
+        let mut stack = Vec::with_capacity(128);
+        stack.extend(self.get_next_step(start_position, start_direction));
+
+        while let Some((current_position, direction)) = stack.pop() {
+            if !visited_positions.insert((current_position, direction)) {
+                continue;
+            }
+
+            let Some(new_position) = self.grid.step(current_position, direction) else {
+                continue;
+            };
+
+            stack.extend(self.get_next_step(new_position, direction));
+        }
+
+        visited_positions
+    }
+
+    fn calculate_number_of_energized_positions(
+        &self,
+        start_position: Position,
+        start_direction: Direction,
+    ) -> usize {
+        self.calculate_energized_positions_after_shot_from(start_position, start_direction)
+            .iter()
+            .map(|(position, _vector)| position)
+            .sorted()
+            .dedup()
+            .count()
+    }
+}
+
+fn part1(input: &str) -> usize {
+    LaserGrid::new_from_input(input)
+        .unwrap()
+        .calculate_number_of_energized_positions((0, 0), Direction::Right)
+}
+
+fn part2(input: &str) -> usize {
+    let laser_grid = LaserGrid::new_from_input(input).unwrap();
+    let height = laser_grid.grid.height();
+    let width = laser_grid.grid.width();
+
+    let vertical_start_configurations = (0..height).flat_map(|start_y| {
+        [((start_y, 0), Direction::Right), ((start_y, width - 1), Direction::Left)]
+    });
+
+    let horizontal_start_configurations = (0..width).flat_map(|start_x| {
+        [((0, start_x), Direction::Down), ((height - 1, start_x), Direction::Up)]
+    });
+
+    vertical_start_configurations
+        .chain(horizontal_start_configurations)
+        .par_bridge()
+        .map(|(start_position, start_direction)| {
+            laser_grid.calculate_number_of_energized_positions(start_position, start_direction)
+        })
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    #[test]
+    fn part1_test() {
+        let input = read_input(DAY, InputType::Test).unwrap();
+        let expected = 46;
+        let result = part1(&input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let input = read_input(DAY, InputType::Test).unwrap();
+        let expected = 51;
+        let result = part2(&input);
+        assert_eq!(result, expected);
+    }
+}
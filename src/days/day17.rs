@@ -0,0 +1,177 @@
+use crate::{
+    grid::Position,
+    parse::{self, ParseResult},
+    search::{self, Path},
+    Answer, Direction, Grid, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 17;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).map_or(NO_ROUTE.into(), Answer::from)
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).map_or(NO_ROUTE.into(), Answer::from)
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn render(input: &str, part: u8) -> Option<String> {
+        let (min_distance, max_distance) = if part == 1 { CRUCIBLE } else { ULTRA_CRUCIBLE };
+        let grid = parse_input(input).unwrap();
+        let path = find_path(&grid, min_distance, max_distance)?;
+        Some(render_path(&grid, &path))
+    }
+}
+
+/// The answer when the crucible cannot stop at the bottom right block, e.g.
+/// an ultra crucible on a grid too small to move four blocks in a line.
+const NO_ROUTE: &str = "no route to the bottom right block";
+
+/// Minimum and maximum number of blocks moved in a straight line.
+const CRUCIBLE: (usize, usize) = (1, 3);
+const ULTRA_CRUCIBLE: (usize, usize) = (4, 10);
+
+fn parse_input(input: &str) -> ParseResult<Grid<usize>> {
+    parse::grid(DAY, input, "a digit", |c| c.to_digit(10).map(|digit| digit as usize))
+}
+
+/// A crucible on a block, with the direction of its last run. The direction is
+/// `None` only at the start, where every direction is allowed.
+type State = (Position, Option<Direction>);
+
+/// Every block the crucible can stop at after turning and then moving between
+/// `min_distance` and `max_distance` blocks in a straight line, with the heat
+/// lost on the way.
+fn successors(
+    grid: &Grid<usize>,
+    (position, last_direction): State,
+    min_distance: usize,
+    max_distance: usize,
+) -> Vec<(State, usize)> {
+    let mut successors = vec![];
+
+    for direction in Direction::ALL {
+        if last_direction.is_some_and(|last| direction == last || direction.reverse() == last) {
+            continue;
+        }
+
+        let mut cost_increase = 0;
+        let mut current = position;
+
+        for distance in 1..=max_distance {
+            let Some(next) = grid.step(current, direction) else {
+                break;
+            };
+
+            current = next;
+            cost_increase += grid[current];
+
+            if distance >= min_distance {
+                successors.push(((current, Some(direction)), cost_increase));
+            }
+        }
+    }
+
+    successors
+}
+
+/// The route with the least heat loss from the top left to the bottom right block.
+/// Since every state is the end of a run, the crucible can only stop at the goal
+/// after moving at least `min_distance` blocks, and `None` if it cannot.
+fn find_path(
+    grid: &Grid<usize>,
+    min_distance: usize,
+    max_distance: usize,
+) -> Option<Path<State, usize>> {
+    let goal = (grid.height() - 1, grid.width() - 1);
+
+    search::dijkstra(
+        ((0, 0), None),
+        |state| successors(grid, *state, min_distance, max_distance),
+        |(position, _)| *position == goal,
+    )
+}
+
+fn find_cost(input: &str, min_distance: usize, max_distance: usize) -> Option<usize> {
+    find_path(&parse_input(input).unwrap(), min_distance, max_distance).map(|path| path.cost)
+}
+
+/// The grid with every block the crucible moves onto replaced by an arrow.
+fn render_path(grid: &Grid<usize>, path: &Path<State, usize>) -> String {
+    let mut picture = grid.map(|heat_loss| char::from_digit(*heat_loss as u32, 10).unwrap());
+
+    for ((mut current, _), (end, direction)) in path.states.iter().tuple_windows() {
+        let direction = direction.unwrap();
+        while current != *end {
+            current = grid.step(current, direction).unwrap();
+            picture[current] = direction.arrow();
+        }
+    }
+
+    picture.to_string()
+}
+
+fn part1(input: &str) -> Option<usize> {
+    find_cost(input, CRUCIBLE.0, CRUCIBLE.1)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    find_cost(input, ULTRA_CRUCIBLE.0, ULTRA_CRUCIBLE.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 102;
+        let result = part1(&get_test_input());
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 94;
+        let result = part2(&get_test_input());
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn part2_must_move_four_blocks_before_stopping() {
+        let input = "111111111111\n999999999991\n999999999991\n999999999991\n999999999991";
+        let expected = 71;
+        let result = part2(input);
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn too_small_for_an_ultra_crucible() {
+        assert_eq!(part1("123"), Some(5));
+        assert_eq!(part2("123"), None);
+        assert_eq!(Day17::part2("123"), Answer::from(NO_ROUTE));
+        assert_eq!(Day17::render("123", 2), None);
+    }
+
+    #[test]
+    fn render_test() {
+        let picture = Day17::render(&get_test_input(), 1).unwrap();
+        assert!(picture.starts_with("2>>34^>>>1323\n"));
+        assert_eq!(picture.matches(['^', '>', 'v', '<']).count(), 28);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    parse::{self, ParseResult, Parser},
+    polygon, Answer, Direction, ParseError, Point, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 18;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_plan(input).map(drop)
+    }
+
+    fn render(input: &str, part: u8) -> Option<String> {
+        let instructions = match part {
+            1 => parse_input(input).unwrap(),
+            _ => parse_input_part2(input).unwrap(),
+        };
+        Some(render_lagoon(&instructions))
+    }
+}
+
+/// The last digit of the hex colour: `0` means R, `1` means D, `2` means L and `3` means U.
+fn direction_from_digit(digit: u32) -> Option<Direction> {
+    match digit {
+        0 => Some(Direction::Right),
+        1 => Some(Direction::Down),
+        2 => Some(Direction::Left),
+        3 => Some(Direction::Up),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    direction: Direction,
+    amount: usize,
+}
+
+/// A line of the dig plan, e.g. `R 6 (#70c710)`: the instruction of part 1,
+/// then the one hidden in the colour for part 2.
+fn parse_line(parser: &mut Parser) -> ParseResult<(Instruction, Instruction)> {
+    let direction = parser.character("`U`, `D`, `L` or `R`", |c| {
+        Direction::try_from(c).ok().filter(|_| "UDLR".contains(c))
+    })?;
+    let amount = parser.integer()?;
+
+    parser.literal("(#")?;
+    let mut amount_hex = 0;
+    for _ in 0..5 {
+        amount_hex = amount_hex * 16 + parser.character("a hex digit", |c| c.to_digit(16))?;
+    }
+    let direction_hex = parser
+        .character("a digit from 0 to 3", |c| c.to_digit(10).and_then(direction_from_digit))?;
+    parser.literal(")")?;
+
+    Ok((
+        Instruction { direction, amount },
+        Instruction { direction: direction_hex, amount: amount_hex as usize },
+    ))
+}
+
+fn parse_plan(input: &str) -> ParseResult<Vec<(Instruction, Instruction)>> {
+    parse::lines(DAY, input, parse_line)
+}
+
+fn parse_input(input: &str) -> ParseResult<Vec<Instruction>> {
+    Ok(parse_plan(input)?.into_iter().map(|(instruction, _)| instruction).collect_vec())
+}
+
+fn parse_input_part2(input: &str) -> ParseResult<Vec<Instruction>> {
+    Ok(parse_plan(input)?.into_iter().map(|(_, instruction)| instruction).collect_vec())
+}
+
+/// The corners of the trench, one per instruction. `None` if they do not
+/// fit in an `i128`.
+fn trench_vertices(instructions: &[Instruction]) -> Option<Vec<Point<i128>>> {
+    let mut current_position = Point::<i128>::new(0, 0);
+    let mut vertices = Vec::with_capacity(instructions.len());
+
+    for instruction in instructions {
+        let step = instruction.direction.vector::<i128>();
+        let amount = i128::try_from(instruction.amount).ok()?;
+        current_position = Point::new(
+            current_position.x.checked_add(step.x.checked_mul(amount)?)?,
+            current_position.y.checked_add(step.y.checked_mul(amount)?)?,
+        );
+        vertices.push(current_position);
+    }
+
+    Some(vertices)
+}
+
+/// Cubic metres of lava the lagoon holds, the trench included. `None` if the
+/// computation overflows an `i128`.
+fn dig_edge_and_calculate_interior(instructions: &[Instruction]) -> Option<u128> {
+    let vertices = trench_vertices(instructions)?;
+    polygon::checked_enclosed_points(&vertices).map(|points| points as u128)
+}
+
+/// The trench corners, one per line, followed by an SVG of the lagoon.
+fn render_lagoon(instructions: &[Instruction]) -> String {
+    let Some(vertices) = trench_vertices(instructions) else {
+        return "the trench does not fit in i128 coordinates".to_owned();
+    };
+
+    let corners = vertices.iter().map(|vertex| format!("{},{}", vertex.x, vertex.y)).join("\n");
+    format!("{corners}\n\n{}", polygon::svg(&vertices))
+}
+
+const TOO_LARGE: &str = "the lagoon is too large for i128";
+
+fn part1(input: &str) -> u128 {
+    let instructions = parse_input(input).unwrap();
+    dig_edge_and_calculate_interior(&instructions).expect(TOO_LARGE)
+}
+
+fn part2(input: &str) -> u128 {
+    let instructions = parse_input_part2(input).unwrap();
+    dig_edge_and_calculate_interior(&instructions).expect(TOO_LARGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 62;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 952408144115;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn trench_vertices_test() {
+        let vertices = trench_vertices(&parse_input(&get_test_input()).unwrap()).unwrap();
+        assert_eq!(vertices.len(), 14);
+        assert_eq!(vertices[0], Point::new(6, 0));
+        assert_eq!(*vertices.last().unwrap(), Point::new(0, 0));
+    }
+
+    #[test]
+    fn overflowing_trench() {
+        let huge = usize::MAX;
+        let instructions = [
+            Instruction { direction: Direction::Right, amount: huge },
+            Instruction { direction: Direction::Down, amount: huge },
+            Instruction { direction: Direction::Left, amount: huge },
+            Instruction { direction: Direction::Up, amount: huge },
+        ];
+        assert_eq!(dig_edge_and_calculate_interior(&instructions), None);
+    }
+
+    #[test]
+    fn area_test2() {
+        fn shoelace_formula(vertices: &[(f64, f64)]) -> f64 {
+            let mut area = 0.0;
+            let mut perimeter = 0.0;
+            let n = vertices.len();
+
+            for i in 0..n {
+                let j = (i + 1) % n;
+                area += (vertices[i].0) * (vertices[j].1);
+                area -= (vertices[j].0) * (vertices[i].1);
+                perimeter +=
+                    (vertices[i].0 - vertices[j].0).abs() + (vertices[i].1 - vertices[j].1).abs();
+            }
+
+            area.abs() / 2.0 + perimeter / 2.0 + 1.0
+        }
+
+        let vertices = vec![(1.0, 1.0), (1.0, 4.0), (5.0, 4.0), (5.0, 1.0)];
+
+        let area = shoelace_formula(&vertices);
+        println!("{}", area);
+    }
+}
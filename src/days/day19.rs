@@ -0,0 +1,541 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 19;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    /// Lists the accepted ranges of part 2, one per line.
+    fn render(input: &str, part: u8) -> Option<String> {
+        (part == 2).then(|| accepted_ranges(input).iter().join("\n"))
+    }
+}
+
+/// A 4D box of machine parts, every rating going from its start to its end inclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MachinePartRange {
+    pub x_start: usize,
+    pub x_end: usize,
+
+    pub m_start: usize,
+    pub m_end: usize,
+
+    pub a_start: usize,
+    pub a_end: usize,
+
+    pub s_start: usize,
+    pub s_end: usize,
+}
+
+impl Display for MachinePartRange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "x={}..={} m={}..={} a={}..={} s={}..={}",
+            self.x_start,
+            self.x_end,
+            self.m_start,
+            self.m_end,
+            self.a_start,
+            self.a_end,
+            self.s_start,
+            self.s_end
+        )
+    }
+}
+
+impl MachinePartRange {
+    /// Every combination of ratings from 1 to 4000.
+    const ALL: MachinePartRange = MachinePartRange {
+        x_start: 1,
+        x_end: 4000,
+        m_start: 1,
+        m_end: 4000,
+        a_start: 1,
+        a_end: 4000,
+        s_start: 1,
+        s_end: 4000,
+    };
+
+    pub fn distinct_possibilities(&self) -> u128 {
+        let x = (self.x_end - self.x_start + 1) as u128;
+        let m = (self.m_end - self.m_start + 1) as u128;
+        let a = (self.a_end - self.a_start + 1) as u128;
+        let s = (self.s_end - self.s_start + 1) as u128;
+
+        x * m * a * s
+    }
+
+    fn get_variable(&self, rating: &RatingVariable) -> (usize, usize) {
+        match rating {
+            RatingVariable::X => (self.x_start, self.x_end),
+            RatingVariable::M => (self.m_start, self.m_end),
+            RatingVariable::A => (self.a_start, self.a_end),
+            RatingVariable::S => (self.s_start, self.s_end),
+        }
+    }
+
+    #[cfg(test)]
+    fn contains(&self, machine_part: &MachinePart) -> bool {
+        let ratings = [RatingVariable::X, RatingVariable::M, RatingVariable::A, RatingVariable::S];
+        ratings.iter().all(|rating| {
+            let (start, end) = self.get_variable(rating);
+            (start..=end).contains(&machine_part.get_variable(rating))
+        })
+    }
+
+    fn change_variable_start(&mut self, rating: &RatingVariable, value: usize) {
+        match rating {
+            RatingVariable::X => self.x_start = value,
+            RatingVariable::M => self.m_start = value,
+            RatingVariable::A => self.a_start = value,
+            RatingVariable::S => self.s_start = value,
+        };
+    }
+
+    fn change_variable_end(&mut self, rating: &RatingVariable, value: usize) {
+        match rating {
+            RatingVariable::X => self.x_end = value,
+            RatingVariable::M => self.m_end = value,
+            RatingVariable::A => self.a_end = value,
+            RatingVariable::S => self.s_end = value,
+        };
+    }
+
+    fn apply_rule(&self, rule: &Rule) -> Vec<(bool, MachinePartRange)> {
+        let rating_value = self.get_variable(&rule.rating);
+        match rule.ordering {
+            Ordering::Less => {
+                if rating_value.0 >= rule.rating_value {
+                    vec![(false, self.clone())]
+                } else if rating_value.1 < rule.rating_value {
+                    vec![(true, self.clone())]
+                } else {
+                    let mut lower_range = self.clone();
+                    let mut upper_range = self.clone();
+
+                    lower_range.change_variable_end(&rule.rating, rule.rating_value - 1);
+                    upper_range.change_variable_start(&rule.rating, rule.rating_value);
+
+                    vec![(true, lower_range), (false, upper_range)]
+                }
+            }
+            Ordering::Greater => {
+                if rating_value.1 <= rule.rating_value {
+                    vec![(false, self.clone())]
+                } else if rule.rating_value < rating_value.0 {
+                    vec![(true, self.clone())]
+                } else {
+                    let mut lower_range = self.clone();
+                    let mut upper_range = self.clone();
+
+                    lower_range.change_variable_end(&rule.rating, rule.rating_value);
+                    upper_range.change_variable_start(&rule.rating, rule.rating_value + 1);
+
+                    vec![(false, lower_range), (true, upper_range)]
+                }
+            }
+            Ordering::Equal => unreachable!(),
+        }
+    }
+
+    fn apply_workflow(
+        machine_part_range: &MachinePartRange,
+        workflow: &Workflow,
+    ) -> Vec<(WorkflowOrDone, MachinePartRange)> {
+        let mut result = vec![];
+        let mut current_machine_part_range = machine_part_range.clone();
+
+        for rule in &workflow.rules {
+            let ranges = current_machine_part_range.apply_rule(rule);
+            let ranges_len = ranges.len();
+            for (meet_requirement, range) in ranges {
+                if meet_requirement {
+                    result.push((rule.destination.clone(), range));
+                    if ranges_len == 1 {
+                        return result;
+                    }
+                } else {
+                    current_machine_part_range = range;
+                }
+            }
+        }
+
+        result.push((workflow.default_gateway.clone(), current_machine_part_range));
+
+        result
+    }
+
+    fn apply_workflows(
+        machine_part_range: &MachinePartRange,
+        workflows: &HashMap<String, Workflow>,
+    ) -> Vec<MachinePartRange> {
+        let mut queue: Vec<(String, MachinePartRange)> =
+            vec![("in".to_string(), machine_part_range.clone())];
+
+        let mut done = vec![];
+
+        while let Some((current_workflow_name, current_machine_part_range)) = queue.pop() {
+            let current_workflow = workflows.get(&current_workflow_name).unwrap();
+            let ranges =
+                MachinePartRange::apply_workflow(&current_machine_part_range, current_workflow);
+
+            for (workflow_or_done, range) in ranges {
+                match workflow_or_done {
+                    WorkflowOrDone::WorkflowName(s) => queue.push((s, range)),
+                    WorkflowOrDone::Accept => done.push(range),
+                    WorkflowOrDone::Reject => (),
+                }
+            }
+        }
+
+        done
+    }
+}
+
+struct MachinePart {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl MachinePart {
+    fn get_variable(&self, rating: &RatingVariable) -> usize {
+        match rating {
+            RatingVariable::X => self.x,
+            RatingVariable::M => self.m,
+            RatingVariable::A => self.a,
+            RatingVariable::S => self.s,
+        }
+    }
+
+    fn apply_rules(&self, rules: &[Rule], default: &WorkflowOrDone) -> WorkflowOrDone {
+        for rule in rules {
+            let rating_value = self.get_variable(&rule.rating);
+            let matched = match rule.ordering {
+                Ordering::Less => rating_value < rule.rating_value,
+                Ordering::Greater => rating_value > rule.rating_value,
+                Ordering::Equal => unreachable!(),
+            };
+            if matched {
+                return rule.destination.clone();
+            }
+        }
+        default.clone()
+    }
+}
+
+struct Workflow {
+    rules: Vec<Rule>,
+    default_gateway: WorkflowOrDone,
+}
+
+#[derive(Clone)]
+enum RatingVariable {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl From<char> for RatingVariable {
+    fn from(value: char) -> Self {
+        match value {
+            'x' => Self::X,
+            'm' => Self::M,
+            'a' => Self::A,
+            's' => Self::S,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum WorkflowOrDone {
+    WorkflowName(String),
+    Accept,
+    Reject,
+}
+
+impl From<&str> for WorkflowOrDone {
+    fn from(value: &str) -> Self {
+        match value {
+            "A" => Self::Accept,
+            "R" => Self::Reject,
+            s => Self::WorkflowName(s.to_string()),
+        }
+    }
+}
+
+/// Every name a workflow sends parts to, with the workflow it is in and the
+/// position it was read at, to check that it names a workflow.
+type Destinations<'a> = Vec<(&'a str, usize, &'a str)>;
+
+impl WorkflowOrDone {
+    /// `A`, `R` or a workflow name, which is noted in `destinations` as sent
+    /// to from `workflow`.
+    fn parse<'a>(
+        parser: &mut Parser<'a>,
+        workflow: &'a str,
+        destinations: &mut Destinations<'a>,
+    ) -> ParseResult<Self> {
+        parser.skip_spaces();
+        let position = parser.position();
+        let name = parser.word()?;
+        destinations.push((workflow, position, name));
+        Ok(WorkflowOrDone::from(name))
+    }
+}
+
+#[derive(Clone)]
+struct Rule {
+    rating: RatingVariable,
+    rating_value: usize,
+    ordering: Ordering,
+    destination: WorkflowOrDone,
+}
+
+impl Rule {
+    /// `a<2006:qkq`, in `workflow`.
+    fn parse<'a>(
+        parser: &mut Parser<'a>,
+        workflow: &'a str,
+        destinations: &mut Destinations<'a>,
+    ) -> ParseResult<Self> {
+        let rating = RatingVariable::from(parser.one_of("xmas")?);
+        let ordering = match parser.one_of("<>")? {
+            '<' => Ordering::Less,
+            _ => Ordering::Greater,
+        };
+        let rating_value = parser.integer()?;
+        parser.literal(":")?;
+        let destination = WorkflowOrDone::parse(parser, workflow, destinations)?;
+
+        Ok(Rule { rating, rating_value, ordering, destination })
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse(DAY, s, |parser| Rule::parse(parser, "", &mut vec![]))
+    }
+}
+
+impl Workflow {
+    /// `px{a<2006:qkq,m>2090:A,rfg}`, with its name.
+    fn parse<'a>(
+        parser: &mut Parser<'a>,
+        destinations: &mut Destinations<'a>,
+    ) -> ParseResult<(&'a str, Self)> {
+        let name = parser.word()?;
+        parser.literal("{")?;
+
+        let mut rules = vec![];
+        while let Some(rule) = parser.optional(|parser| {
+            let rule = Rule::parse(parser, name, destinations)?;
+            parser.literal(",")?;
+            Ok(rule)
+        }) {
+            rules.push(rule);
+        }
+        let default_gateway = WorkflowOrDone::parse(parser, name, destinations)?;
+        parser.literal("}")?;
+
+        Ok((name, Workflow { rules, default_gateway }))
+    }
+}
+
+impl MachinePart {
+    /// `{x=787,m=2655,a=1222,s=2876}`
+    fn parse(parser: &mut Parser) -> ParseResult<Self> {
+        parser.literal("{")?;
+        let ratings = parser.key_values(Parser::integer)?;
+        let rating = |name| ratings.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+
+        match (rating("x"), rating("m"), rating("a"), rating("s")) {
+            (Some(x), Some(m), Some(a), Some(s)) => {
+                parser.literal("}")?;
+                Ok(MachinePart { x, m, a, s })
+            }
+            _ => Err(parser.error("ratings for each of x, m, a and s")),
+        }
+    }
+}
+
+/// The position of a destination that leads back to a workflow it is
+/// reached from, following the workflows from `workflow` on. `visited` holds
+/// the workflows on the current path as `false` and those done as `true`.
+fn find_cycle<'a>(
+    sends_to: &HashMap<&'a str, Vec<(usize, &'a str)>>,
+    workflow: &'a str,
+    visited: &mut HashMap<&'a str, bool>,
+) -> Option<usize> {
+    visited.insert(workflow, false);
+    for &(position, next) in sends_to.get(workflow).into_iter().flatten() {
+        match visited.get(next) {
+            Some(false) => return Some(position),
+            Some(true) => {}
+            None => {
+                if let Some(position) = find_cycle(sends_to, next, visited) {
+                    return Some(position);
+                }
+            }
+        }
+    }
+    visited.insert(workflow, true);
+    None
+}
+
+fn parse_input(input: &str) -> ParseResult<(HashMap<String, Workflow>, Vec<MachinePart>)> {
+    parse::parse(DAY, input, |parser| {
+        let mut destinations = vec![];
+        let workflows = parser.lines(|parser| Workflow::parse(parser, &mut destinations))?;
+        if !workflows.iter().any(|(name, _)| *name == "in") {
+            return Err(parser.error("a workflow named `in`"));
+        }
+
+        // Parts must end up accepted or rejected, so every destination has to
+        // exist and no workflow may send them back around in a circle.
+        let mut sends_to: HashMap<&str, Vec<(usize, &str)>> =
+            workflows.iter().map(|(name, _)| (*name, vec![])).collect();
+        for &(workflow, position, name) in &destinations {
+            if sends_to.contains_key(name) {
+                sends_to.get_mut(workflow).unwrap().push((position, name));
+            } else if name != "A" && name != "R" {
+                return Err(parser.error_at(position, "`A`, `R` or a workflow name"));
+            }
+        }
+        if let Some(position) = find_cycle(&sends_to, "in", &mut HashMap::new()) {
+            return Err(parser.error_at(position, "a workflow that does not lead back here"));
+        }
+        parser.skip_whitespace();
+        let machine_parts = parser.lines(MachinePart::parse)?;
+
+        let workflows =
+            workflows.into_iter().map(|(name, workflow)| (name.to_string(), workflow)).collect();
+        Ok((workflows, machine_parts))
+    })
+}
+
+fn process_machine_part(workflows: &HashMap<String, Workflow>, machine_part: &MachinePart) -> bool {
+    let mut current_step = WorkflowOrDone::WorkflowName("in".to_string());
+
+    while let WorkflowOrDone::WorkflowName(ref workflow_name) = current_step {
+        let current_workflow = workflows.get(workflow_name).unwrap();
+        current_step =
+            machine_part.apply_rules(&current_workflow.rules, &current_workflow.default_gateway);
+    }
+
+    match current_step {
+        WorkflowOrDone::Accept => true,
+        WorkflowOrDone::Reject => false,
+        WorkflowOrDone::WorkflowName(_) => unreachable!(),
+    }
+}
+
+fn part1(input: &str) -> usize {
+    let (workflows, machine_parts) = parse_input(input).unwrap();
+
+    machine_parts
+        .iter()
+        .filter(|machine_part| process_machine_part(&workflows, machine_part))
+        .map(|machine_part| machine_part.x + machine_part.m + machine_part.a + machine_part.s)
+        .sum()
+}
+
+/// The disjoint ranges of machine parts that the workflows accept.
+pub fn accepted_ranges(input: &str) -> Vec<MachinePartRange> {
+    let (workflows, _) = parse_input(input).unwrap();
+    MachinePartRange::apply_workflows(&MachinePartRange::ALL, &workflows)
+}
+
+fn part2(input: &str) -> u128 {
+    accepted_ranges(input).iter().map(|range| range.distinct_possibilities()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 19114;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 167409079868000;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn undefined_and_cyclic_destinations() {
+        assert!(Day19::check("in{x<10:ab,R}\nab{A}\n\n{x=1,m=2,a=3,s=4}").is_ok());
+
+        let error = Day19::check("in{x<10:ab,cd}\nab{A}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+
+        let error = Day19::check("in{x<10:ab,R}\nab{m>5:in,A}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+    }
+
+    #[test]
+    fn ranges_agree_with_single_parts() {
+        let input = get_test_input();
+        let (workflows, machine_parts) = parse_input(&input).unwrap();
+        let ranges = accepted_ranges(&input);
+
+        for machine_part in &machine_parts {
+            let containing = ranges.iter().filter(|range| range.contains(machine_part)).count();
+            let expected = usize::from(process_machine_part(&workflows, machine_part));
+            assert_eq!(containing, expected);
+        }
+    }
+
+    #[test]
+    fn parse_rule() {
+        let rule = "m>2090:A".parse::<Rule>().unwrap();
+        assert!(matches!(rule.rating, RatingVariable::M));
+        assert_eq!((rule.ordering, rule.rating_value), (Ordering::Greater, 2090));
+        assert!(matches!(rule.destination, WorkflowOrDone::Accept));
+
+        let error = "q<10:A".parse::<Rule>().err().unwrap();
+        assert_eq!((error.column, error.expected.as_str()), (1, "one of `xmas`"));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    pulse::{self, Machine},
+    Answer, ParseError, Solution,
+};
+
+const DAY: u8 = 20;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        Machine::new_from_input(DAY, input).map(drop)
+    }
+}
+
+fn part1(input: &str) -> usize {
+    let mut machine = Machine::new_from_input(DAY, input).unwrap();
+
+    for _ in 0..1000 {
+        machine.push_button();
+    }
+
+    machine.high_pulses() * machine.low_pulses()
+}
+
+fn part2(input: &str) -> usize {
+    let machine = Machine::new_from_input(DAY, input).unwrap();
+    pulse::presses_until_low(machine, "rx").unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    #[test]
+    fn part1_test1() {
+        let expected = 32000000;
+        let result = part1(&read_input(DAY, InputType::Other("test1".to_string())).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part1_test2() {
+        let expected = 11687500;
+        let result = part1(&read_input(DAY, InputType::Other("test2".to_string())).unwrap());
+        assert_eq!(result, expected);
+    }
+}
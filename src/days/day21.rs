@@ -0,0 +1,257 @@
+use crate::{
+    grid::{Grid, Position},
+    parse::{self, ParseResult},
+    search, Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 21;
+
+/// Steps the elf takes on the infinite garden of part 2.
+const PART2_STEPS: usize = 26501365;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input, 64).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input, PART2_STEPS).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_garden(input).map(drop)
+    }
+
+    /// Cross-checks the extrapolation of part 2 against walking the garden.
+    fn render(input: &str, part: u8) -> Option<String> {
+        (part == 2).then(|| render_cross_check(input))
+    }
+}
+
+/// The garden with its starting position `S` turned into a plot. The garden
+/// has to be square, as the infinite garden of part 2 repeats it with the same
+/// period in both directions.
+fn parse_garden(input: &str) -> ParseResult<(Grid<char>, Position)> {
+    parse::parse(DAY, input, |parser| {
+        let mut grid = parser.grid("`.`, `#` or `S`", |c| ".#S".contains(c).then_some(c))?;
+        let width = grid.width();
+        if grid.height() > width {
+            return Err(parse::cell_error(DAY, input, (width, 0), "the end of a square garden"));
+        }
+        if grid.height() < width {
+            return Err(parser.error(format!("{width} rows, as many as the garden is wide")));
+        }
+        let Some(start) = grid.find(|c| *c == 'S') else {
+            return Err(parser.error("a row with the start `S`"));
+        };
+        grid[start] = '.';
+
+        Ok((grid, start))
+    })
+}
+
+fn neighbours(grid: &Grid<char>, position: Position) -> Vec<Position> {
+    grid.neighbours4(position).filter(|neighbour| grid[*neighbour] != '#').collect_vec()
+}
+
+/// Neighbours on the grid repeated infinitely in every direction.
+fn neighbours2(grid: &Grid<char>, y: isize, x: isize) -> Vec<(isize, isize)> {
+    let neighbours = vec![(y - 1, x), (y, x - 1), (y + 1, x), (y, x + 1)];
+
+    neighbours
+        .into_iter()
+        .filter(|(y, x)| {
+            let y = (y.rem_euclid(grid.height() as isize)) as usize;
+            let x = (x.rem_euclid(grid.width() as isize)) as usize;
+            grid[(y, x)] != '#'
+        })
+        .collect_vec()
+}
+
+fn part1(input: &str, steps: usize) -> usize {
+    let (grid, start_position) = parse_garden(input).unwrap();
+
+    // A plot reached in fewer steps can be reached again by stepping back and forth,
+    // as long as the number of remaining steps is even.
+    search::bfs_distances_within(start_position, steps, |position| neighbours(&grid, *position))
+        .values()
+        .filter(|distance| *distance % 2 == steps % 2)
+        .count()
+}
+
+/// Number of plots at most `steps` steps away whose distance has the parity of
+/// `steps`, i.e. the plots the elf can end on, given the number of plots at
+/// every distance.
+fn count_reachable(plots_at_distance: &[usize], steps: usize) -> usize {
+    plots_at_distance.iter().take(steps + 1).skip(steps % 2).step_by(2).sum()
+}
+
+/// Number of plots at every distance from the start, up to `max_distance`,
+/// on the infinitely repeated garden.
+fn plots_at_distance(grid: &Grid<char>, start: (isize, isize), max_distance: usize) -> Vec<usize> {
+    let distances =
+        search::bfs_distances_within(start, max_distance, |(y, x)| neighbours2(grid, *y, *x));
+
+    let mut plots_at_distance = vec![0; max_distance + 1];
+    for distance in distances.into_values() {
+        plots_at_distance[distance] += 1;
+    }
+    plots_at_distance
+}
+
+/// Number of equal second differences in a row after which the growth is taken
+/// to be quadratic.
+const STABLE_SECOND_DIFFERENCES: usize = 3;
+
+/// Continues `samples`, taken at evenly spaced indices, up to index `target` if
+/// their last second differences are equal. `None` if they have not settled yet.
+fn extrapolate_quadratic(samples: &[usize], target: usize) -> Option<usize> {
+    let samples = samples.iter().map(|sample| *sample as i128).collect_vec();
+    let first_differences = samples.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+    let second_differences =
+        first_differences.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+
+    let stable = second_differences.iter().rev().take(STABLE_SECOND_DIFFERENCES);
+    if second_differences.len() < STABLE_SECOND_DIFFERENCES || !stable.clone().all_equal() {
+        return None;
+    }
+
+    let last = samples.len() - 1;
+    let remaining = (target - last) as i128;
+    let second_difference = *second_differences.last().unwrap();
+    let first_difference = *first_differences.last().unwrap();
+
+    let value = samples[last]
+        + remaining * first_difference
+        + second_difference * remaining * (remaining + 1) / 2;
+    Some(value as usize)
+}
+
+fn parse_infinite_garden(input: &str) -> ParseResult<(Grid<char>, (isize, isize))> {
+    let (grid, (start_y, start_x)) = parse_garden(input)?;
+    Ok((grid, (start_y as isize, start_x as isize)))
+}
+
+/// Walks the infinite garden step by step. Only feasible for small step counts,
+/// but makes no assumptions about the shape of the garden, so it serves to
+/// cross-check [`part2`].
+fn part2_brute_force(input: &str, steps: usize) -> usize {
+    let (grid, start) = parse_infinite_garden(input).unwrap();
+    count_reachable(&plots_at_distance(&grid, start, steps), steps)
+}
+
+/// Once the walk has spread over a few copies of the garden, every further
+/// copy it reaches repeats what happened in the previous ones, so the number of
+/// reachable plots after `remainder + k * size` steps grows quadratically in
+/// `k`. The walk is simulated until that growth has settled and then
+/// extrapolated.
+fn part2(input: &str, steps: usize) -> usize {
+    let (grid, start) = parse_infinite_garden(input).unwrap();
+
+    let period = grid.width();
+    let remainder = steps % period;
+    let target = steps / period;
+    let mut sample_count = STABLE_SECOND_DIFFERENCES + 3;
+
+    loop {
+        let max_distance = remainder + (sample_count - 1) * period;
+        if max_distance >= steps {
+            return count_reachable(&plots_at_distance(&grid, start, steps), steps);
+        }
+
+        let plots_at_distance = plots_at_distance(&grid, start, max_distance);
+        let samples = (0..sample_count)
+            .map(|k| count_reachable(&plots_at_distance, remainder + k * period))
+            .collect_vec();
+
+        if let Some(count) = extrapolate_quadratic(&samples, target) {
+            return count;
+        }
+
+        sample_count *= 2;
+    }
+}
+
+/// The plots reached by walking the garden and by [`part2`] for a few step
+/// counts past the samples that part 2 starts extrapolating from, with the
+/// remainder of [`PART2_STEPS`].
+fn render_cross_check(input: &str) -> String {
+    let (grid, _) = parse_garden(input).unwrap();
+    let period = grid.width();
+    let remainder = PART2_STEPS % period;
+
+    let header = format!("{:>8} {:>14} {:>14}", "steps", "walked", "extrapolated");
+    let rows = [6, 8, 10].map(|k| {
+        let steps = remainder + k * period;
+        let walked = part2_brute_force(input, steps);
+        format!("{steps:>8} {walked:>14} {:>14}", part2(input, steps))
+    });
+    [header].into_iter().chain(rows).join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 16;
+        let result = part1(&get_test_input(), 6);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn garden_must_be_square() {
+        let error = Day21::check("...\n.S.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        let error = Day21::check("..\n.S\n..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 16733044;
+        let result = part2(&get_test_input(), 5000);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let examples = [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004), (1000, 668697)];
+        for (steps, expected) in examples {
+            assert_eq!(part2(&get_test_input(), steps), expected, "{steps} steps");
+        }
+    }
+
+    #[test]
+    fn render_test() {
+        let picture = Day21::render(&get_test_input(), 2).unwrap();
+        let rows = picture.lines().skip(1).collect_vec();
+        assert_eq!(rows.len(), 3);
+        for row in rows {
+            let [_, walked, extrapolated] = row.split_whitespace().collect_vec()[..] else {
+                panic!("unexpected row {row}");
+            };
+            assert_eq!(walked, extrapolated);
+        }
+        assert!(Day21::render(&get_test_input(), 1).is_none());
+    }
+
+    #[test]
+    fn part2_agrees_with_brute_force() {
+        for steps in [64, 150, 333, 400] {
+            let expected = part2_brute_force(&get_test_input(), steps);
+            assert_eq!(part2(&get_test_input(), steps), expected, "{steps} steps");
+        }
+    }
+}
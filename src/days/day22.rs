@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{self, ParseResult},
+    Answer, ParseError, Point3, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 22;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+}
+
+struct Brick {
+    /// The corner with the lowest coordinates.
+    start: Point3<usize>,
+    /// The corner with the highest coordinates.
+    end: Point3<usize>,
+}
+
+impl Brick {
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> {
+        (self.start.x..=self.end.x).cartesian_product(self.start.y..=self.end.y)
+    }
+
+    fn height(&self) -> usize {
+        self.end.z - self.start.z + 1
+    }
+}
+
+/// A brick per line, as the two opposite corners `x,y,z~x,y,z`.
+fn parse_input(input: &str) -> ParseResult<Vec<Brick>> {
+    parse::lines(DAY, input, |parser| {
+        let a: Point3<usize> = parser.point3()?;
+        parser.literal("~")?;
+        let b: Point3<usize> = parser.point3()?;
+
+        Ok(Brick {
+            start: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            end: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        })
+    })
+}
+
+/// Which bricks rest on which once all of them have fallen.
+struct Stack {
+    supported_by: Vec<Vec<usize>>,
+    supports: Vec<Vec<usize>>,
+}
+
+fn settle(mut bricks: Vec<Brick>) -> Stack {
+    bricks.sort_by_key(|brick| brick.start.z);
+
+    // The height of the highest settled cube in every column, and its brick.
+    let mut tops: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut supported_by = vec![vec![]; bricks.len()];
+    let mut supports = vec![vec![]; bricks.len()];
+
+    for (index, brick) in bricks.iter().enumerate() {
+        let below = brick.footprint().filter_map(|column| tops.get(&column)).copied().collect_vec();
+        let rest_height = below.iter().map(|(height, _)| *height).max().unwrap_or(0);
+
+        for (_, support) in below.into_iter().filter(|(height, _)| *height == rest_height).unique()
+        {
+            supported_by[index].push(support);
+            supports[support].push(index);
+        }
+
+        for column in brick.footprint() {
+            tops.insert(column, (rest_height + brick.height(), index));
+        }
+    }
+
+    Stack { supported_by, supports }
+}
+
+impl Stack {
+    /// Number of other bricks that fall if `brick` is removed.
+    fn chain_reaction(&self, brick: usize) -> usize {
+        let mut remaining_supports = self.supported_by.iter().map(Vec::len).collect_vec();
+        let mut falling = vec![brick];
+        let mut fallen = 0;
+
+        while let Some(current) = falling.pop() {
+            for above in &self.supports[current] {
+                remaining_supports[*above] -= 1;
+                if remaining_supports[*above] == 0 {
+                    fallen += 1;
+                    falling.push(*above);
+                }
+            }
+        }
+
+        fallen
+    }
+}
+
+fn part1(input: &str) -> usize {
+    let stack = settle(parse_input(input).unwrap());
+
+    (0..stack.supports.len())
+        .filter(|brick| {
+            stack.supports[*brick].iter().all(|above| stack.supported_by[*above].len() > 1)
+        })
+        .count()
+}
+
+fn part2(input: &str) -> usize {
+    let stack = settle(parse_input(input).unwrap());
+
+    (0..stack.supports.len()).map(|brick| stack.chain_reaction(brick)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 5;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 7;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
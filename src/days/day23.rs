@@ -0,0 +1,145 @@
+use crate::{
+    graph::{self, Graph},
+    grid::Position,
+    parse::{self, ParseResult},
+    search, Answer, Direction, Grid, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 23;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_map(input).map(drop)
+    }
+}
+
+/// Most crossings the map may have, so that the start, the end and the
+/// crossings fit in the `u64` bit set of [`longest_path`].
+const MAX_CROSSINGS: usize = 62;
+
+/// The map with the start, the path in its top row, and the end, the path in
+/// its bottom row. The end has to be reachable down the slopes, which makes it
+/// reachable without them too.
+fn parse_map(input: &str) -> ParseResult<(Grid<char>, Position, Position)> {
+    let tile = |c| ".#^>v<".contains(c).then_some(c);
+    let grid = parse::grid(DAY, input, "`.`, `#` or a slope", tile)?;
+    let path_in_row = |row: usize| {
+        let column = grid.rows().nth(row).and_then(|row| row.iter().position(|c| *c == '.'));
+        column
+            .map(|column| (row, column))
+            .ok_or_else(|| parse::cell_error(DAY, input, (row, 0), "a row with a path `.`"))
+    };
+    let start = path_in_row(0)?;
+    let end = path_in_row(grid.height().saturating_sub(1))?;
+
+    if let Some(crossing) = crossings(&grid).nth(MAX_CROSSINGS) {
+        let expected = format!("at most {MAX_CROSSINGS} crossings");
+        return Err(parse::cell_error(DAY, input, crossing, expected));
+    }
+    let reachable = search::bfs_distances(start, |position| successors(&grid, *position, true));
+    if !reachable.contains_key(&end) {
+        let expected = "an end that can be reached from the start";
+        return Err(parse::cell_error(DAY, input, end, expected));
+    }
+
+    Ok((grid, start, end))
+}
+
+/// The paths that lead in more than two directions.
+fn crossings(grid: &Grid<char>) -> impl Iterator<Item = Position> + '_ {
+    grid.find_all(|c| *c != '#').filter(|position| {
+        grid.neighbours4(*position).filter(|next| grid[*next] != '#').count() > 2
+    })
+}
+
+/// Paths a hiker can take from `position`. On slippery slopes the only way is
+/// downhill.
+fn successors(grid: &Grid<char>, position: Position, slippery: bool) -> Vec<Position> {
+    match Direction::try_from(grid[position]) {
+        Ok(slope) if slippery => grid.step(position, slope).into_iter().collect_vec(),
+        _ => grid.neighbours4(position).filter(|next| grid[*next] != '#').collect_vec(),
+    }
+}
+
+/// The trails as a graph between the start (node 0), the end (node 1) and all
+/// crossings, with edges weighted by the number of steps between them.
+fn trail_graph(input: &str, slippery: bool) -> Graph {
+    let (grid, start, end) = parse_map(input).unwrap();
+
+    let junctions = [start, end].into_iter().chain(crossings(&grid)).collect_vec();
+
+    graph::compress(&junctions, |position| successors(&grid, *position, slippery)).0
+}
+
+/// Length of the longest path from `from` to `to` that visits no node in
+/// `visited`, a bit set of node indices.
+fn longest_path(graph: &Graph, from: usize, to: usize, visited: u64) -> Option<u64> {
+    if from == to {
+        return Some(0);
+    }
+
+    graph
+        .edges(from)
+        .iter()
+        .filter(|(next, _)| visited & (1 << next) == 0)
+        .filter_map(|(next, length)| {
+            longest_path(graph, *next, to, visited | (1 << next)).map(|rest| rest + length)
+        })
+        .max()
+}
+
+fn longest_hike(input: &str, slippery: bool) -> u64 {
+    let graph = trail_graph(input, slippery);
+    longest_path(&graph, 0, 1, 1).expect("the map has been checked to reach the end")
+}
+
+fn part1(input: &str) -> u64 {
+    longest_hike(input, true)
+}
+
+fn part2(input: &str) -> u64 {
+    longest_hike(input, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 94;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let error = Day23::check("#.###\n#.<.#\n###.#\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 4));
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 154;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{
+    parse::{self, ParseResult},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+use mathru::{
+    algebra::linear::{
+        matrix::{General, Solve},
+        vector::Vector,
+    },
+    matrix, vector,
+};
+use num::{BigInt, BigRational, Signed, ToPrimitive, Zero};
+
+const DAY: u8 = 24;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input, 200000000000000, 400000000000000).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+}
+
+/// Part 1 with the crossings computed in `f64`, to compare against the exact
+/// solution.
+pub struct Day24Float;
+
+impl Solution for Day24Float {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        count_crossings(input, 200000000000000, 400000000000000, Arithmetic::Float).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+}
+
+type Vector3 = [i128; 3];
+
+struct Hailstone {
+    position: Vector3,
+    velocity: Vector3,
+}
+
+fn parse_input(input: &str) -> ParseResult<Vec<Hailstone>> {
+    parse::lines(DAY, input, |parser| {
+        let (position, velocity) = parser.vector3::<i128>()?;
+        Ok(Hailstone {
+            position: [position.x, position.y, position.z],
+            velocity: [velocity.x, velocity.y, velocity.z],
+        })
+    })
+}
+
+/// How the crossing point of two paths is computed.
+#[derive(Clone, Copy, Debug)]
+enum Arithmetic {
+    /// `f64`, which can round crossings right at the edge of the test area
+    /// the wrong way at the magnitude of the real input.
+    Float,
+    /// Exact rationals.
+    Exact,
+}
+
+fn rational(value: i128) -> BigRational {
+    BigRational::from_integer(BigInt::from(value))
+}
+
+/// Whether the paths of the two hailstones in the XY plane cross inside the
+/// test area, in the future of both.
+fn crosses_within_exact(a: &Hailstone, b: &Hailstone, min_coord: i128, max_coord: i128) -> bool {
+    let [px1, py1, _] = a.position;
+    let [vx1, vy1, _] = a.velocity;
+    let [px2, py2, _] = b.position;
+    let [vx2, vy2, _] = b.velocity;
+
+    // Solve p1 + t * v1 = p2 + s * v2 with Cramer's rule.
+    let determinant = vx2 * vy1 - vx1 * vy2;
+    if determinant == 0 {
+        return false;
+    }
+
+    let (dx, dy) = (px2 - px1, py2 - py1);
+    let t = BigRational::new(BigInt::from(vx2 * dy - vy2 * dx), BigInt::from(determinant));
+    let s = BigRational::new(BigInt::from(vx1 * dy - vy1 * dx), BigInt::from(determinant));
+
+    if t.is_negative() || s.is_negative() {
+        return false;
+    }
+
+    let area = rational(min_coord)..=rational(max_coord);
+    let x = rational(px1) + &t * rational(vx1);
+    let y = rational(py1) + &t * rational(vy1);
+
+    area.contains(&x) && area.contains(&y)
+}
+
+fn crosses_within_float(a: &Hailstone, b: &Hailstone, min_coord: i128, max_coord: i128) -> bool {
+    let [px1, py1, _] = a.position.map(|value| value as f64);
+    let [vx1, vy1, _] = a.velocity.map(|value| value as f64);
+    let [px2, py2, _] = b.position.map(|value| value as f64);
+    let [vx2, vy2, _] = b.velocity.map(|value| value as f64);
+
+    let a: General<f64> = matrix![
+        vx1, -vx2;
+        vy1, -vy2
+    ];
+
+    let b: Vector<f64> = vector![
+        px2 - px1;
+        py2 - py1
+    ];
+
+    let coefficients: Vector<f64> = match a.solve(&b) {
+        Ok(v) => v,
+        Err(_) => return false,
+    };
+
+    let t = coefficients[0];
+    let s = coefficients[1];
+
+    let crossed_in_past = t < 0.0 || s < 0.0;
+
+    if crossed_in_past {
+        return false;
+    }
+
+    let intersection = (px1 + t * vx1, py1 + t * vy1);
+
+    intersection.0 >= min_coord as f64
+        && intersection.0 <= max_coord as f64
+        && intersection.1 >= min_coord as f64
+        && intersection.1 <= max_coord as f64
+}
+
+fn count_crossings(input: &str, min_coord: i128, max_coord: i128, arithmetic: Arithmetic) -> usize {
+    let hailstones = parse_input(input).unwrap();
+
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter(|(a, b)| match arithmetic {
+            Arithmetic::Float => crosses_within_float(a, b, min_coord, max_coord),
+            Arithmetic::Exact => crosses_within_exact(a, b, min_coord, max_coord),
+        })
+        .count()
+}
+
+fn part1(input: &str, min_coord: i128, max_coord: i128) -> usize {
+    count_crossings(input, min_coord, max_coord, Arithmetic::Exact)
+}
+
+fn subtract(a: Vector3, b: Vector3) -> Vector3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Vector3, b: Vector3) -> Vector3 {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// The matrix `M` with `M * b == a × b` for every `b`.
+fn cross_matrix(a: Vector3) -> [Vector3; 3] {
+    [[0, -a[2], a[1]], [a[2], 0, -a[0]], [-a[1], a[0], 0]]
+}
+
+/// Solves the augmented system `rows` by Gauss-Jordan elimination. `None` if
+/// the system has no unique solution.
+fn solve_linear_system(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let unknowns = rows.len();
+
+    for column in 0..unknowns {
+        let pivot = (column..unknowns).find(|row| !rows[*row][column].is_zero())?;
+        rows.swap(column, pivot);
+
+        let pivot_value = rows[column][column].clone();
+        rows[column].iter_mut().for_each(|value| *value /= &pivot_value);
+
+        let pivot_row = rows[column].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            let factor = row[column].clone();
+            if index == column || factor.is_zero() {
+                continue;
+            }
+            for (value, pivot_value) in row.iter_mut().zip(&pivot_row) {
+                *value -= pivot_value * &factor;
+            }
+        }
+    }
+
+    Some(rows.into_iter().map(|row| row[unknowns].clone()).collect_vec())
+}
+
+/// Position and velocity of the rock that hits every hailstone.
+///
+/// A rock `P + t * V` hits hailstone `p + t * v` iff `(P - p) × (V - v) = 0`.
+/// Expanding that, the only non-linear term `P × V` is the same for every
+/// hailstone, so subtracting the equations of hailstones `i` and `j` leaves
+/// three linear equations:
+///
+/// `(v_i - v_j) × P + (p_j - p_i) × V = p_j × v_j - p_i × v_i`
+///
+/// Two such pairs give six equations for the six unknowns.
+fn throw_rock(hailstones: &[Hailstone]) -> Option<(Vec<BigRational>, Vec<BigRational>)> {
+    let equations = |i: &Hailstone, j: &Hailstone| {
+        let position_matrix = cross_matrix(subtract(i.velocity, j.velocity));
+        let velocity_matrix = cross_matrix(subtract(j.position, i.position));
+        let rhs = subtract(cross(j.position, j.velocity), cross(i.position, i.velocity));
+
+        (0..3)
+            .map(|row| {
+                position_matrix[row]
+                    .iter()
+                    .chain(&velocity_matrix[row])
+                    .chain([&rhs[row]])
+                    .map(|value| rational(*value))
+                    .collect_vec()
+            })
+            .collect_vec()
+    };
+
+    // Hailstones with parallel paths give dependent equations, so try
+    // triples until the system has a unique solution.
+    hailstones.iter().tuple_combinations().find_map(|(first, second, third)| {
+        let rows = [equations(first, second), equations(first, third)].concat();
+        let solution = solve_linear_system(rows)?;
+        let (position, velocity) = solution.split_at(3);
+        Some((position.to_vec(), velocity.to_vec()))
+    })
+}
+
+fn part2(input: &str) -> i128 {
+    let hailstones = parse_input(input).unwrap();
+    let (position, _velocity) = throw_rock(&hailstones).unwrap();
+
+    let sum = position.into_iter().sum::<BigRational>();
+    assert!(sum.is_integer(), "the rock must start at integer coordinates, got {sum}");
+    sum.to_integer().to_i128().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 2;
+        let result = part1(&get_test_input(), 7, 27);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part1_float_test() {
+        let expected = 2;
+        let result = count_crossings(&get_test_input(), 7, 27, Arithmetic::Float);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn crossing_on_the_edge_of_the_area() {
+        // The paths cross at exactly (10, 10).
+        let a = Hailstone { position: [0, 10, 0], velocity: [1, 0, 0] };
+        let b = Hailstone { position: [10, 0, 0], velocity: [0, 1, 0] };
+        assert!(crosses_within_exact(&a, &b, 0, 10));
+        assert!(!crosses_within_exact(&a, &b, 0, 9));
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = 47;
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn rock_of_the_example() {
+        let (position, velocity) = throw_rock(&parse_input(&get_test_input()).unwrap()).unwrap();
+        assert_eq!(position, [24, 13, 10].map(rational));
+        assert_eq!(velocity, [-3, 1, 2].map(rational));
+    }
+
+    #[test]
+    fn rock_hits_every_real_hailstone() {
+        let hailstones = parse_input(&read_input(DAY, InputType::Real).unwrap()).unwrap();
+        let (position, velocity) = throw_rock(&hailstones).unwrap();
+        let to_integers = |values: Vec<BigRational>| -> Vector3 {
+            assert!(values.iter().all(BigRational::is_integer));
+            let values = values.iter().map(|value| value.to_integer().to_i128().unwrap());
+            values.collect_vec().try_into().unwrap()
+        };
+        let (position, velocity) = (to_integers(position), to_integers(velocity));
+
+        for hailstone in &hailstones {
+            let relative_position = subtract(position, hailstone.position);
+            let relative_velocity = subtract(velocity, hailstone.velocity);
+            assert_eq!(cross(relative_position, relative_velocity), [0, 0, 0]);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    graph::{self, Graph, NodeIndex},
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+const DAY: u8 = 25;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+}
+
+/// A component per line, followed by the components it is wired to, e.g.
+/// `jqt: rhn xhk nvd`.
+fn parse_input(input: &str) -> ParseResult<Graph> {
+    let lines = parse::lines(DAY, input, |parser| {
+        let component = parser.word()?;
+        parser.literal(":")?;
+        Ok((component, parser.list(Parser::word)?))
+    })?;
+    let connections = lines
+        .into_iter()
+        .flat_map(|(component, others)| others.into_iter().map(move |other| (component, other)))
+        .collect_vec();
+
+    let mut index = NodeIndex::default();
+    let connections =
+        connections.into_iter().map(|(a, b)| (index.index(a), index.index(b))).collect_vec();
+
+    let mut graph = Graph::new(index.len());
+    for (a, b) in connections {
+        graph.add_undirected_edge(a, b, 1);
+    }
+    Ok(graph)
+}
+
+fn part1(input: &str) -> usize {
+    let graph = parse_input(input).unwrap();
+    let cut = graph::minimum_cut(&graph).unwrap();
+    assert_eq!(cut.weight, 3, "the puzzle promises that three wires have to be cut");
+
+    cut.part.len() * (graph.len() - cut.part.len())
+}
+
+/// There is no puzzle for part 2, just the button that starts the snow.
+fn part2(_input: &str) -> &'static str {
+    "Merry Christmas!"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = 54;
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}
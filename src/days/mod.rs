@@ -0,0 +1,66 @@
+use crate::solution::Entry;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day07b;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day14b;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every registered solution, ordered by day. The main implementation of a day
+/// comes first, followed by its named variants.
+pub fn registry() -> Vec<Entry> {
+    vec![
+        Entry::of::<day01::Day01>(),
+        Entry::variant::<day01::Day01V1>("v1"),
+        Entry::variant::<day01::Day01V2>("v2"),
+        Entry::of::<day02::Day02>(),
+        Entry::of::<day03::Day03>(),
+        Entry::of::<day04::Day04>(),
+        Entry::of::<day05::Day05>(),
+        Entry::of::<day06::Day06>(),
+        Entry::of::<day07::Day07>(),
+        Entry::variant::<day07b::Day07b>("b"),
+        Entry::of::<day08::Day08>(),
+        Entry::of::<day09::Day09>(),
+        Entry::of::<day10::Day10>(),
+        Entry::of::<day11::Day11>(),
+        Entry::of::<day12::Day12>(),
+        Entry::of::<day13::Day13>(),
+        Entry::of::<day14::Day14>(),
+        Entry::variant::<day14b::Day14b>("b"),
+        Entry::of::<day15::Day15>(),
+        Entry::of::<day16::Day16>(),
+        Entry::of::<day17::Day17>(),
+        Entry::of::<day18::Day18>(),
+        Entry::of::<day19::Day19>(),
+        Entry::of::<day20::Day20>(),
+        Entry::of::<day21::Day21>(),
+        Entry::of::<day22::Day22>(),
+        Entry::of::<day23::Day23>(),
+        Entry::of::<day24::Day24>(),
+        Entry::variant::<day24::Day24Float>("float"),
+        Entry::of::<day25::Day25>(),
+    ]
+}
@@ -0,0 +1,942 @@
+//! Random puzzle inputs, one generator per day.
+//!
+//! A generator turns a `u64` seed and a size into an input that follows the
+//! format of the day and keeps the promises its puzzle makes, such as a single
+//! loop through `S` on day 10 or a single conjunction feeding `rx` on day 20,
+//! so that every solution can run on it. The same seed and size always give
+//! the same input. What the size measures depends on the day and is noted on
+//! each generator; [`Generator::default_size`] is about the size of the real
+//! inputs.
+
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng as _, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    grid::{Grid, Position},
+    Direction,
+};
+
+/// The random number generator every generator draws from. ChaCha is used
+/// because its output for a seed does not change between versions of `rand`.
+pub type Rng = ChaCha8Rng;
+
+/// The generator of inputs for one day.
+pub struct Generator {
+    pub day: u8,
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    const fn new(day: u8, default_size: usize, generate: fn(&mut Rng, usize) -> String) -> Self {
+        Self { day, default_size, generate }
+    }
+
+    /// The input for `seed` and `size`, ending with a newline. A `size` of 0
+    /// is taken as 1, as no day has a valid empty input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let mut rng = Rng::seed_from_u64(seed);
+        let mut input = (self.generate)(&mut rng, size.max(1));
+        if !input.ends_with('\n') {
+            input.push('\n');
+        }
+        input
+    }
+}
+
+/// Every generator, ordered by day.
+pub fn generators() -> Vec<Generator> {
+    vec![
+        Generator::new(1, 1000, day01),
+        Generator::new(2, 100, day02),
+        Generator::new(3, 140, day03),
+        Generator::new(4, 200, day04),
+        Generator::new(5, 30, day05),
+        Generator::new(6, 4, day06),
+        Generator::new(7, 1000, day07),
+        Generator::new(8, 300, day08),
+        Generator::new(9, 200, day09),
+        Generator::new(10, 139, day10),
+        Generator::new(11, 140, day11),
+        Generator::new(12, 1000, day12),
+        Generator::new(13, 100, day13),
+        Generator::new(14, 100, day14),
+        Generator::new(15, 4000, day15),
+        Generator::new(16, 110, day16),
+        Generator::new(17, 141, day17),
+        Generator::new(18, 30, day18),
+        Generator::new(19, 500, day19),
+        Generator::new(20, 12, day20),
+        Generator::new(21, 65, day21),
+        Generator::new(22, 1200, day22),
+        Generator::new(23, 6, day23),
+        Generator::new(24, 300, day24),
+        Generator::new(25, 750, day25),
+    ]
+}
+
+pub fn generator(day: u8) -> Option<Generator> {
+    generators().into_iter().find(|generator| generator.day == day)
+}
+
+/// `count` distinct names of `length` lowercase letters, none of them in
+/// `reserved`.
+fn names(rng: &mut Rng, count: usize, length: u32, reserved: &[&str]) -> Vec<String> {
+    assert!(count + reserved.len() <= 26_usize.pow(length), "not enough names of length {length}");
+
+    let mut names = HashSet::new();
+    let mut ordered = vec![];
+    while ordered.len() < count {
+        let name = (0..length).map(|_| rng.gen_range('a'..='z')).collect::<String>();
+        if !reserved.contains(&name.as_str()) && names.insert(name.clone()) {
+            ordered.push(name);
+        }
+    }
+    ordered
+}
+
+/// A random polyomino within a `size` by `size` grid: a connected set of
+/// cells without holes, in which no two cells touch only at a corner. Its
+/// outline is therefore a single simple loop.
+fn polyomino(rng: &mut Rng, size: usize) -> Grid<bool> {
+    let size = size.max(1);
+    let mut cells = Grid::filled(size, size, false);
+    let mut members = vec![(size / 2, size / 2)];
+    cells[members[0]] = true;
+
+    while members.len() < size * size / 2 {
+        let member = *members.choose(rng).unwrap();
+        let direction = *Direction::ALL.choose(rng).unwrap();
+        if let Some(next) = cells.step(member, direction).filter(|next| !cells[*next]) {
+            cells[next] = true;
+            members.push(next);
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        // Fill the holes: the empty cells that cannot be reached from the border.
+        let mut outside = HashSet::new();
+        let mut stack = cells
+            .positions()
+            .filter(|&(row, column)| {
+                row == 0 || column == 0 || row == size - 1 || column == size - 1
+            })
+            .filter(|position| !cells[*position])
+            .collect_vec();
+        while let Some(position) = stack.pop() {
+            if outside.insert(position) {
+                stack.extend(cells.neighbours4(position).filter(|next| !cells[*next]));
+            }
+        }
+        for position in cells.positions().collect_vec() {
+            if !cells[position] && !outside.contains(&position) {
+                cells[position] = true;
+                changed = true;
+            }
+        }
+
+        // Join cells that only touch at a corner.
+        for (row, column) in (0..size - 1).cartesian_product(0..size - 1) {
+            let block =
+                [(row, column), (row, column + 1), (row + 1, column), (row + 1, column + 1)];
+            let [a, b, c, d] = block.map(|position| cells[position]);
+            if a == d && b == c && a != b {
+                let empty = if a { [block[1], block[2]] } else { [block[0], block[3]] };
+                cells[*empty.choose(rng).unwrap()] = true;
+                changed = true;
+            }
+        }
+    }
+
+    cells
+}
+
+/// The outline of a polyomino, as the corner of the grid lines it starts at
+/// and the unit steps along the grid lines around it, clockwise. Corners are
+/// `(row, column)` with `(0, 0)` the top left corner of the grid.
+fn outline(cells: &Grid<bool>) -> (Position, Vec<Direction>) {
+    // Whether the cell at `delta` from the corner, the cell below and right
+    // of it being at `(0, 0)`, belongs to the polyomino.
+    let inside = |corner, delta| cells.offset(corner, delta).is_some_and(|cell| cells[cell]);
+    // Whether the grid line from the corner in `direction` separates a cell of
+    // the polyomino from one that is not.
+    let is_edge = |corner, direction| match direction {
+        Direction::Right => inside(corner, (-1, 0)) != inside(corner, (0, 0)),
+        Direction::Left => inside(corner, (-1, -1)) != inside(corner, (0, -1)),
+        Direction::Down => inside(corner, (0, -1)) != inside(corner, (0, 0)),
+        Direction::Up => inside(corner, (-1, -1)) != inside(corner, (-1, 0)),
+    };
+
+    let start = cells.find(|cell| *cell).expect("a polyomino has at least one cell");
+    let mut steps = vec![];
+    let mut corner = start;
+    let mut direction = Direction::Right;
+    loop {
+        steps.push(direction);
+        corner = walk(corner, direction, 1);
+        if corner == start {
+            return (start, steps);
+        }
+        direction = Direction::ALL
+            .into_iter()
+            .find(|next| *next != direction.reverse() && is_edge(corner, *next))
+            .expect("every corner of the outline has two edges");
+    }
+}
+
+/// The corner `length` grid lines away from `corner` in `direction`.
+fn walk((row, column): Position, direction: Direction, length: usize) -> Position {
+    let vector = direction.vector::<isize>();
+    let length = length as isize;
+    (
+        row.checked_add_signed(vector.y * length).unwrap(),
+        column.checked_add_signed(vector.x * length).unwrap(),
+    )
+}
+
+/// `count` increasing coordinates starting at 0, with gaps between `1` and
+/// `max_gap`.
+fn coordinates(rng: &mut Rng, count: usize, max_gap: usize) -> Vec<usize> {
+    (0..count)
+        .scan(0, |coordinate, index| {
+            if index > 0 {
+                *coordinate += rng.gen_range(1..=max_gap);
+            }
+            Some(*coordinate)
+        })
+        .collect()
+}
+
+fn is_prime(n: usize) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+/// `size` lines of letters, digits and spelled-out digits, each with at least
+/// one digit.
+fn day01(rng: &mut Rng, size: usize) -> String {
+    const SPELLED: [&str; 9] =
+        ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+    (0..size)
+        .map(|_| {
+            let mut tokens = (0..rng.gen_range(1..=8))
+                .map(|_| match rng.gen_range(0..4) {
+                    0 => SPELLED.choose(rng).unwrap().to_string(),
+                    1 => rng.gen_range('1'..='9').to_string(),
+                    _ => (0..rng.gen_range(1..=4)).map(|_| rng.gen_range('a'..='z')).collect(),
+                })
+                .collect_vec();
+            let digit = rng.gen_range('1'..='9').to_string();
+            tokens.insert(rng.gen_range(0..=tokens.len()), digit);
+            tokens.concat()
+        })
+        .join("\n")
+}
+
+/// `size` games of up to six draws each.
+fn day02(rng: &mut Rng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let draws = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    colors.shuffle(rng);
+                    colors[..rng.gen_range(1..=3)]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                        .join(", ")
+                })
+                .join("; ");
+            format!("Game {id}: {draws}")
+        })
+        .join("\n")
+}
+
+/// A `size` by `size` schematic of numbers of up to three digits and symbols.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row = String::new();
+            while row.len() < size {
+                let room = size - row.len();
+                match rng.gen_range(0..20) {
+                    0..=2 => {
+                        let digits = rng.gen_range(1..=3).min(room);
+                        row.push(rng.gen_range('1'..='9'));
+                        (1..digits).for_each(|_| row.push(rng.gen_range('0'..='9')));
+                        if row.len() < size {
+                            row.push('.');
+                        }
+                    }
+                    3 => row.push(*b"*#+$/@=%&-".choose(rng).unwrap() as char),
+                    _ => row.push('.'),
+                }
+            }
+            row
+        })
+        .join("\n")
+}
+
+/// `size` cards with ten winning numbers and twenty-five numbers each. Cards
+/// never win copies of cards past the end of the table.
+fn day04(rng: &mut Rng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let mut numbers = (1..100).collect_vec();
+            numbers.shuffle(rng);
+            let (winning, others) = numbers.split_at(10);
+
+            // Mostly losing cards, so that the number of copies stays small.
+            let matches = match rng.gen_range(0..5) {
+                0..=2 => 0,
+                _ => rng.gen_range(1..=3),
+            };
+            let matches = matches.min(size - id);
+            let mut ours = winning[..matches].iter().chain(&others[..25 - matches]).collect_vec();
+            ours.shuffle(rng);
+
+            let winning = winning.iter().map(|number| format!("{number:>2}")).join(" ");
+            let ours = ours.iter().map(|number| format!("{number:>2}")).join(" ");
+            format!("Card {id:>3}: {winning} | {ours}")
+        })
+        .join("\n")
+}
+
+/// Twenty seeds and seven maps of `size` ranges each.
+fn day05(rng: &mut Rng, size: usize) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    const LIMIT: u64 = 1 << 32;
+
+    let seeds = (0..10)
+        .map(|_| {
+            let start = rng.gen_range(0..LIMIT / 2);
+            format!("{start} {}", rng.gen_range(1..LIMIT / 16))
+        })
+        .join(" ");
+
+    let maps = MAPS.map(|name| {
+        // Disjoint source ranges, from the sorted breakpoints taken in pairs.
+        let mut breakpoints = (0..2 * size).map(|_| rng.gen_range(0..LIMIT)).collect_vec();
+        breakpoints.sort();
+        breakpoints.dedup();
+        let ranges = breakpoints
+            .chunks_exact(2)
+            .map(|pair| {
+                let length = pair[1] - pair[0];
+                let destination = rng.gen_range(0..LIMIT - length);
+                format!("{destination} {} {length}", pair[0])
+            })
+            .collect_vec()
+            .join("\n");
+        format!("{name} map:\n{ranges}")
+    });
+
+    format!("seeds: {seeds}\n\n{}", maps.join("\n\n"))
+}
+
+/// Up to four races, so that part 2 still fits in a `u64`. Every race can be
+/// won, and so can the single race of part 2.
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let best = |time: u64| time / 2 * (time - time / 2);
+    let races = loop {
+        let races = (0..size.clamp(1, 4))
+            .map(|_| {
+                let time = rng.gen_range(10..100_u64);
+                (time, rng.gen_range(best(time) / 2..best(time)))
+            })
+            .collect_vec();
+        let time: u64 = races.iter().map(|(time, _)| time).join("").parse().unwrap();
+        let distance: u64 = races.iter().map(|(_, distance)| distance).join("").parse().unwrap();
+        if distance < best(time) {
+            break races;
+        }
+    };
+
+    let times = races.iter().map(|(time, _)| format!("{time:>5}")).join("");
+    let distances = races.iter().map(|(_, distance)| format!("{distance:>5}")).join("");
+    format!("Time:    {times}\nDistance:{distances}")
+}
+
+/// `size` hands and bids.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let hand = (0..5).map(|_| *b"AKQJT98765432".choose(rng).unwrap() as char);
+            format!("{} {}", hand.collect::<String>(), rng.gen_range(1..=1000))
+        })
+        .join("\n")
+}
+
+/// Four ghosts, `AAA` among them, each running around a loop of a prime
+/// number of nodes between `size` and `2 * size` that passes a single `Z`
+/// node, so that part 2 is the least common multiple of the loop lengths.
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(2, 1500);
+    let mut primes = (size..=2 * size + 20).filter(|n| is_prime(*n)).collect_vec();
+    primes.shuffle(rng);
+
+    // Names end in `A` for starts, in `Z` for ends and in any other letter
+    // otherwise.
+    let mut used = HashSet::from(["AAA".to_owned(), "ZZZ".to_owned()]);
+    let mut name = |rng: &mut Rng, last: Option<char>| loop {
+        let last = last.unwrap_or_else(|| rng.gen_range('B'..='Y'));
+        let name = format!("{}{}{last}", rng.gen_range('A'..='Z'), rng.gen_range('A'..='Z'));
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let mut nodes = vec![];
+    for (ghost, length) in primes.into_iter().take(4).enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_owned(), "ZZZ".to_owned()),
+            _ => (name(rng, Some('A')), name(rng, Some('Z'))),
+        };
+        let ring = [end].into_iter().chain((1..length).map(|_| name(rng, None))).collect_vec();
+        nodes.push((start, ring[1 % length].clone()));
+        for (index, node) in ring.iter().enumerate() {
+            nodes.push((node.clone(), ring[(index + 1) % length].clone()));
+        }
+    }
+    nodes.shuffle(rng);
+
+    let instructions = (0..size).map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' });
+    let nodes = nodes.iter().map(|(node, next)| format!("{node} = ({next}, {next})")).join("\n");
+    format!("{}\n\n{nodes}", instructions.collect::<String>())
+}
+
+/// `size` histories of polynomials of up to fifth degree.
+fn day09(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let coefficients = (0..=rng.gen_range(0..=5)).map(|_| rng.gen_range(-9..=9_i64));
+            let coefficients = coefficients.collect_vec();
+            (0..rng.gen_range(8..=21_i64))
+                .map(|x| coefficients.iter().rev().fold(0, |value, c| value * x + c))
+                .join(" ")
+        })
+        .join("\n")
+}
+
+/// The outline of a random polyomino as the loop, on a `size + 1` by
+/// `size + 1` grid of otherwise random pipes.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let (start, steps) = outline(&polyomino(rng, size));
+    let mut grid =
+        Grid::from_fn(size + 1, size + 1, |_| *b"|-LJ7F...".choose(rng).unwrap() as char);
+
+    let mut loop_tiles = vec![];
+    let mut corner = start;
+    for (arrived, leaving) in steps.iter().circular_tuple_windows() {
+        corner = grid.step(corner, *arrived).unwrap();
+        let pipe = match (arrived.reverse(), *leaving) {
+            (a, b) if a.is_vertical() && b.is_vertical() => '|',
+            (a, b) if a.is_horizontal() && b.is_horizontal() => '-',
+            (Direction::Up, Direction::Right) | (Direction::Right, Direction::Up) => 'L',
+            (Direction::Up, Direction::Left) | (Direction::Left, Direction::Up) => 'J',
+            (Direction::Down, Direction::Left) | (Direction::Left, Direction::Down) => '7',
+            _ => 'F',
+        };
+        grid[corner] = pipe;
+        loop_tiles.push(corner);
+    }
+
+    // Only the loop may connect to `S`.
+    let start = *loop_tiles.choose(rng).unwrap();
+    grid[start] = 'S';
+    let loop_tiles = loop_tiles.into_iter().collect::<HashSet<_>>();
+    for neighbour in grid.neighbours4(start).collect_vec() {
+        if !loop_tiles.contains(&neighbour) {
+            grid[neighbour] = '.';
+        }
+    }
+
+    grid.to_string()
+}
+
+/// A `size` by `size` image with about a tenth of its rows and columns empty.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let empty_rows = (0..size).filter(|_| rng.gen_bool(0.1)).collect::<HashSet<_>>();
+    let empty_columns = (0..size).filter(|_| rng.gen_bool(0.1)).collect::<HashSet<_>>();
+
+    Grid::from_fn(size, size, |(row, column)| {
+        let empty = empty_rows.contains(&row) || empty_columns.contains(&column);
+        if !empty && rng.gen_bool(0.04) {
+            '#'
+        } else {
+            '.'
+        }
+    })
+    .to_string()
+}
+
+/// `size` rows of up to twenty springs, each with at least one damaged.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let length = rng.gen_range(1..=20);
+            let mut springs = (0..length).map(|_| rng.gen_bool(0.4)).collect_vec();
+            springs[rng.gen_range(0..length)] = true;
+
+            let groups = springs.iter().dedup_with_count().filter(|(_, damaged)| **damaged);
+            let groups = groups.map(|(count, _)| count).join(",");
+            let conditions = springs
+                .iter()
+                .map(|damaged| match (rng.gen_bool(0.4), damaged) {
+                    (true, _) => '?',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                })
+                .collect::<String>();
+            format!("{conditions} {groups}")
+        })
+        .join("\n")
+}
+
+/// `size` patterns of ash and rocks with exactly one line of reflection, and
+/// exactly one other line that a single smudge keeps from being one.
+fn day13(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| mirror_pattern(rng).to_string()).join("\n")
+}
+
+/// Number of cells that differ between the two sides of the line between
+/// rows `line - 1` and `line`.
+fn reflection_errors(pattern: &Grid<char>, line: usize) -> usize {
+    let reach = line.min(pattern.height() - line);
+    (0..reach)
+        .map(|offset| {
+            let (above, below) = (pattern.row(line - 1 - offset), pattern.row(line + offset));
+            above.iter().zip(below).filter(|(a, b)| a != b).count()
+        })
+        .sum()
+}
+
+fn mirror_pattern(rng: &mut Rng) -> Grid<char> {
+    loop {
+        let height = rng.gen_range(5..=17);
+        let width = rng.gen_range(5..=17);
+        // The line of reflection between rows `row_line - 1` and `row_line`
+        // must leave rows outside its reach for the smudge.
+        let row_line = rng.gen_range(1..height);
+        let row_reach = row_line.min(height - row_line);
+        if 2 * row_reach == height {
+            continue;
+        }
+        let column_line = rng.gen_range(1..width);
+        let column_reach = column_line.min(width - column_line);
+
+        // Each cell takes the value of its mirror image closest to the top
+        // left, so the pattern is symmetric along both lines.
+        let reflect = |index: usize, line: usize, reach: usize| match index {
+            index if index >= line && index < line + reach => 2 * line - 1 - index,
+            index => index,
+        };
+        let values = Grid::from_fn(width, height, |_| if rng.gen_bool(0.5) { '#' } else { '.' });
+        let mut pattern = Grid::from_fn(width, height, |(row, column)| {
+            values[(reflect(row, row_line, row_reach), reflect(column, column_line, column_reach))]
+        });
+
+        let outside_rows = (0..height)
+            .filter(|row| !(row_line - row_reach..row_line + row_reach).contains(row))
+            .collect_vec();
+        let smudge = (
+            *outside_rows.choose(rng).unwrap(),
+            rng.gen_range(column_line - column_reach..column_line + column_reach),
+        );
+        pattern[smudge] = if pattern[smudge] == '#' { '.' } else { '#' };
+
+        let transposed = pattern.transposed();
+        let errors = (1..height)
+            .map(|line| reflection_errors(&pattern, line))
+            .chain((1..width).map(|line| reflection_errors(&transposed, line)))
+            .collect_vec();
+        if errors.iter().filter(|errors| **errors == 0).count() == 1
+            && errors.iter().filter(|errors| **errors == 1).count() == 1
+        {
+            return if rng.gen_bool(0.5) { pattern } else { transposed };
+        }
+    }
+}
+
+/// A `size` by `size` platform.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    Grid::from_fn(size, size, |_| match rng.gen_range(0..20) {
+        0..=3 => 'O',
+        4..=6 => '#',
+        _ => '.',
+    })
+    .to_string()
+}
+
+/// `size` steps on lenses with labels of up to six letters.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    let labels = (0..size / 8 + 1)
+        .map(|_| (0..rng.gen_range(1..=6)).map(|_| rng.gen_range('a'..='z')).collect::<String>())
+        .collect_vec();
+
+    (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            match rng.gen_range(0..3) {
+                0 => format!("{label}-"),
+                _ => format!("{label}={}", rng.gen_range(1..=9)),
+            }
+        })
+        .join(",")
+}
+
+/// A `size` by `size` contraption of mirrors and splitters.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    Grid::from_fn(size.max(1), size.max(1), |_| match rng.gen_range(0..20) {
+        0..=2 => *b"/\\|-".choose(rng).unwrap() as char,
+        _ => '.',
+    })
+    .to_string()
+}
+
+/// A `size` by `size` city of heat losses, at least five blocks wide so that
+/// the ultra crucible can reach the factory.
+fn day17(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    Grid::from_fn(size, size, |_| rng.gen_range('1'..='9')).to_string()
+}
+
+/// The outline of a random polyomino in a `size` by `size` grid, stretched to
+/// random lengths for part 1 and to much longer ones for part 2.
+fn day18(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let (start, steps) = outline(&polyomino(rng, size));
+    let max_gap = 0xfffff / size;
+    let coordinates = [10, max_gap]
+        .map(|max_gap| [coordinates(rng, size + 1, max_gap), coordinates(rng, size + 1, max_gap)]);
+
+    let mut instructions = vec![];
+    let mut corner = start;
+    for (direction, run) in &steps.iter().group_by(|direction| **direction) {
+        let next = walk(corner, direction, run.count());
+        let [amount, hex_amount] = coordinates.each_ref().map(|[rows, columns]| {
+            rows[corner.0].abs_diff(rows[next.0]) + columns[corner.1].abs_diff(columns[next.1])
+        });
+        instructions.push((direction, amount, hex_amount));
+        corner = next;
+    }
+
+    instructions
+        .into_iter()
+        .map(|(direction, amount, hex_amount)| {
+            let (letter, digit) = match direction {
+                Direction::Right => ('R', 0),
+                Direction::Down => ('D', 1),
+                Direction::Left => ('L', 2),
+                Direction::Up => ('U', 3),
+            };
+            format!("{letter} {amount} (#{hex_amount:05x}{digit})")
+        })
+        .join("\n")
+}
+
+/// A tree of `size` workflows below `in`, followed by `size` machine parts.
+fn day19(rng: &mut Rng, size: usize) -> String {
+    let mut names = names(rng, size, 3, &["in"]).into_iter();
+    let mut pending = vec!["in".to_owned()];
+    let mut workflows = vec![];
+
+    while let Some(name) = pending.pop() {
+        let mut destination =
+            |rng: &mut Rng| match rng.gen_bool(0.6).then(|| names.next()).flatten() {
+                Some(workflow) => {
+                    pending.push(workflow.clone());
+                    workflow
+                }
+                None => ["A", "R"].choose(rng).unwrap().to_string(),
+            };
+        let rules = (0..rng.gen_range(1..=3))
+            .map(|_| {
+                let rating = *b"xmas".choose(rng).unwrap() as char;
+                let ordering = if rng.gen_bool(0.5) { '<' } else { '>' };
+                let value = rng.gen_range(1..=4000);
+                format!("{rating}{ordering}{value}:{}", destination(rng))
+            })
+            .collect_vec();
+        workflows.push(format!("{name}{{{},{}}}", rules.join(","), destination(rng)));
+    }
+    workflows.shuffle(rng);
+
+    let parts = (0..size.max(1))
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}")
+        })
+        .join("\n");
+    format!("{}\n\n{parts}", workflows.join("\n"))
+}
+
+/// Four binary counters of `size` flip-flops each, like the real networks.
+/// Each counter counts button presses up to a random odd period and then
+/// resets, which its conjunction reports to the one feeding `rx`.
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let bits = size.clamp(2, 16);
+    let mut names = names(rng, 4 * (bits + 2) + 1, 2, &["rx"]).into_iter();
+    let feeder = names.next().unwrap();
+    let mut lines = vec![format!("&{feeder} -> rx")];
+    let mut firsts = vec![];
+
+    for _ in 0..4 {
+        // The highest and lowest bits are set, so the counter resets to zero.
+        let period = rng.gen_range(1 << (bits - 1)..1 << bits) | 1;
+        let flip_flops = names.by_ref().take(bits).collect_vec();
+        let hub = names.next().unwrap();
+        let inverter = names.next().unwrap();
+
+        let mut hub_outputs = vec![flip_flops[0].clone(), inverter.clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = flip_flops.get(bit + 1).into_iter().cloned().collect_vec();
+            if period & (1 << bit) != 0 {
+                outputs.push(hub.clone());
+            } else {
+                hub_outputs.push(flip_flop.clone());
+            }
+            outputs.shuffle(rng);
+            lines.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+        hub_outputs.shuffle(rng);
+        lines.push(format!("&{hub} -> {}", hub_outputs.join(", ")));
+        lines.push(format!("&{inverter} -> {feeder}"));
+        firsts.push(flip_flops[0].clone());
+    }
+
+    lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+    lines.shuffle(rng);
+    lines.join("\n")
+}
+
+/// A `2 * size + 1` square garden with the start in the middle. Its middle
+/// row and column and its border are free of rocks, as in the real input.
+fn day21(rng: &mut Rng, size: usize) -> String {
+    let side = 2 * size + 1;
+    Grid::from_fn(side, side, |(row, column)| {
+        let free = [row, column].iter().any(|index| [0, size, side - 1].contains(index));
+        match (row, column) {
+            _ if (row, column) == (size, size) => 'S',
+            _ if !free && rng.gen_bool(0.12) => '#',
+            _ => '.',
+        }
+    })
+    .to_string()
+}
+
+/// `size` bricks of up to four cubes over a ten by ten area, none of them
+/// overlapping.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    let mut occupied = HashSet::new();
+    let mut bricks = vec![];
+
+    while bricks.len() < size {
+        let axis = rng.gen_range(0..3);
+        let length = rng.gen_range(0..4);
+        let start = [rng.gen_range(0..10), rng.gen_range(0..10), rng.gen_range(1..=size / 4 + 10)];
+        let mut end = start;
+        end[axis] += length;
+        if axis < 2 && end[axis] >= 10 {
+            continue;
+        }
+
+        let cubes = (start[0]..=end[0])
+            .cartesian_product(start[1]..=end[1])
+            .cartesian_product(start[2]..=end[2])
+            .collect_vec();
+        if cubes.iter().all(|cube| !occupied.contains(cube)) {
+            occupied.extend(cubes);
+            bricks.push(format!(
+                "{},{},{}~{},{},{}",
+                start[0], start[1], start[2], end[0], end[1], end[2]
+            ));
+        }
+    }
+
+    bricks.join("\n")
+}
+
+/// A `size` by `size` lattice of crossings joined by trails, like the real
+/// map. The trails only lead right and down on the slopes, the start is above
+/// the top left crossing and the end below the bottom right one. `size` is at
+/// most 6, as the longest hike of part 2 takes exponentially longer.
+fn day23(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 6);
+    let [rows, columns] = [(); 2].map(|_| {
+        let first = rng.gen_range(1..=3);
+        coordinates(rng, size, 6).into_iter().map(|coordinate| first + 3 * coordinate).collect_vec()
+    });
+    let height = rows[size - 1] + rng.gen_range(2..=4);
+    let width = columns[size - 1] + rng.gen_range(2..=4);
+    let mut grid = Grid::filled(width, height, '#');
+
+    for (&row, &column) in rows.iter().cartesian_product(&columns) {
+        grid[(row, column)] = '.';
+    }
+    for (&row, pair) in rows.iter().cartesian_product(columns.iter().tuple_windows::<(_, _)>()) {
+        (pair.0 + 1..*pair.1).for_each(|column| grid[(row, column)] = '.');
+        grid[(row, pair.0 + 1)] = '>';
+        grid[(row, pair.1 - 1)] = '>';
+    }
+    for (pair, &column) in rows.iter().tuple_windows::<(_, _)>().cartesian_product(&columns) {
+        (pair.0 + 1..*pair.1).for_each(|row| grid[(row, column)] = '.');
+        grid[(pair.0 + 1, column)] = 'v';
+        grid[(pair.1 - 1, column)] = 'v';
+    }
+    (0..rows[0]).for_each(|row| grid[(row, columns[0])] = '.');
+    (rows[size - 1] + 1..height).for_each(|row| grid[(row, columns[size - 1])] = '.');
+
+    grid.to_string()
+}
+
+/// `size` hailstones that a single rock thrown from an integer position with
+/// an integer velocity hits at integer times.
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let rock = [(); 3].map(|_| rng.gen_range(250_000_000_000_000..350_000_000_000_000_i64));
+    let rock_velocity = [(); 3].map(|_| rng.gen_range(-300..=300_i64));
+    let mut times = HashSet::new();
+
+    (0..size.max(3))
+        .map(|_| {
+            let time = loop {
+                let time = rng.gen_range(1..100_000_000_000_i64);
+                if times.insert(time) {
+                    break time;
+                }
+            };
+            // Never parallel to the rock on any axis.
+            let velocity =
+                rock_velocity.map(|v| v + rng.gen_range(1..=100) * [-1, 1].choose(rng).unwrap());
+            let position =
+                [0, 1, 2].map(|axis| rock[axis] + time * (rock_velocity[axis] - velocity[axis]));
+            format!(
+                "{}, {}, {} @ {}, {}, {}",
+                position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+            )
+        })
+        .join("\n")
+}
+
+/// Two groups of `size` components, wired so that each group only falls
+/// apart when at least four of its wires are cut, and joined by three wires.
+fn day25(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let mut names = names(rng, 2 * size, 3, &[]);
+    names.shuffle(rng);
+
+    let mut wires = HashSet::new();
+    for group in names.chunks(size) {
+        for (index, component) in group.iter().enumerate() {
+            wires.insert((component, &group[(index + 1) % size]));
+            wires.insert((component, &group[(index + 2) % size]));
+            wires.insert((component, group.choose(rng).unwrap()));
+        }
+    }
+    let (left, right) = names.split_at(size);
+    for (a, b) in left.choose_multiple(rng, 3).zip(right.choose_multiple(rng, 3)) {
+        wires.insert((a, b));
+    }
+
+    // Every wire is listed once, under either of its ends.
+    let mut listed = HashSet::new();
+    let mut lines: HashMap<&String, Vec<&String>> = HashMap::new();
+    for (a, b) in wires.into_iter().sorted() {
+        if a == b || !listed.insert((a.min(b), a.max(b))) {
+            continue;
+        }
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        lines.entry(a).or_default().push(b);
+    }
+
+    lines
+        .into_iter()
+        .sorted()
+        .map(|(component, others)| format!("{component}: {}", others.iter().join(" ")))
+        .collect_vec()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days::registry, pulse};
+
+    #[test]
+    fn smallest_inputs_are_valid() {
+        for generator in generators() {
+            let input = generator.generate(1, 0);
+            for entry in registry().iter().filter(|entry| entry.day == generator.day) {
+                assert!((entry.check)(&input).is_ok(), "{} cannot parse:\n{input}", entry.name());
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for generator in generators() {
+            let size = generator.default_size.min(20);
+            assert_eq!(generator.generate(7, size), generator.generate(7, size));
+        }
+    }
+
+    #[test]
+    fn every_solution_parses_generated_inputs() {
+        for generator in generators() {
+            for seed in 0..5 {
+                let input = generator.generate(seed, generator.default_size.min(12));
+                for entry in registry().iter().filter(|entry| entry.day == generator.day) {
+                    if let Err(err) = (entry.check)(&input) {
+                        panic!("{} cannot parse seed {seed}:\n{err}\n{input}", entry.name());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn variants_agree_on_generated_inputs() {
+        let entries = registry();
+        for (day, seed) in [(1, 3), (14, 5)] {
+            let input = generator(day).unwrap().generate(seed, 30);
+            let answers = entries
+                .iter()
+                .filter(|entry| entry.day == day)
+                .map(|entry| (entry.part1)(&input))
+                .collect_vec();
+            assert!(answers.len() > 1 && answers.iter().all_equal(), "day {day}: {answers:?}");
+        }
+    }
+
+    #[test]
+    fn pulse_network_analysis_agrees_with_simulation() {
+        for seed in 0..3 {
+            let input = generator(20).unwrap().generate(seed, 3);
+            let mut machine = pulse::Machine::new_from_input(20, &input).unwrap();
+            let mut low_to_rx = false;
+            while !low_to_rx {
+                machine.push_button_with(|_, to, pulse| {
+                    low_to_rx |= to == "rx" && pulse == pulse::Pulse::Low
+                });
+            }
+
+            let analysed = pulse::Machine::new_from_input(20, &input).unwrap();
+            assert_eq!(pulse::presses_until_low(analysed, "rx"), Some(machine.presses()));
+        }
+    }
+}
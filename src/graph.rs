@@ -0,0 +1,287 @@
+//! Weighted graphs over numbered nodes, and the algorithms that need one built
+//! explicitly instead of the implicit state spaces of [`crate::search`].
+
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+};
+
+use itertools::Itertools;
+
+/// A directed graph with nodes `0..len()` and weighted edges.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    adjacency: Vec<Vec<(usize, u64)>>,
+}
+
+impl Graph {
+    pub fn new(len: usize) -> Self {
+        Self { adjacency: vec![vec![]; len] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64) {
+        self.adjacency[from].push((to, weight));
+    }
+
+    /// Adds the edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: u64) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// Nodes reachable over a single edge, with the weight of that edge.
+    pub fn edges(&self, node: usize) -> &[(usize, u64)] {
+        &self.adjacency[node]
+    }
+}
+
+/// Numbers arbitrary node labels for a [`Graph`].
+#[derive(Debug, Clone)]
+pub struct NodeIndex<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N> Default for NodeIndex<N> {
+    fn default() -> Self {
+        Self { nodes: vec![], indices: HashMap::new() }
+    }
+}
+
+impl<N: Clone + Hash + Eq> NodeIndex<N> {
+    /// Index of the node, numbering it if it is new.
+    pub fn index(&mut self, node: N) -> usize {
+        let next = self.nodes.len();
+        *self.indices.entry(node.clone()).or_insert_with(|| {
+            self.nodes.push(node);
+            next
+        })
+    }
+
+    pub fn get(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    pub fn node(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// Collapses every corridor, a chain of nodes with no other way in or out,
+/// into a single edge between the `junctions` at its ends, weighted with its
+/// length.
+///
+/// `successors` may be directed, e.g. for one-way slopes: a corridor only
+/// becomes an edge if it can be walked from one junction to the next. Walks
+/// that end anywhere but at a junction are dropped. Nodes `0..junctions.len()`
+/// of the returned graph are the junctions in the given order.
+pub fn compress<N, I>(junctions: &[N], mut successors: impl FnMut(&N) -> I) -> (Graph, NodeIndex<N>)
+where
+    N: Clone + Hash + Eq,
+    I: IntoIterator<Item = N>,
+{
+    let mut index = NodeIndex::default();
+    for junction in junctions {
+        index.index(junction.clone());
+    }
+    let junction_set: HashSet<&N> = junctions.iter().collect();
+
+    let mut graph = Graph::new(junctions.len());
+    for (from, junction) in junctions.iter().enumerate() {
+        for first in successors(junction) {
+            let mut previous = junction.clone();
+            let mut current = first;
+            let mut length = 1;
+
+            let end = loop {
+                if junction_set.contains(&current) {
+                    break Some(current);
+                }
+                let next = successors(&current).into_iter().find(|next| *next != previous);
+                let Some(next) = next else {
+                    break None;
+                };
+                previous = std::mem::replace(&mut current, next);
+                length += 1;
+            };
+
+            if let Some(end) = end.filter(|end| end != junction) {
+                graph.add_edge(from, index.get(&end).unwrap(), length);
+            }
+        }
+    }
+
+    (graph, index)
+}
+
+/// A cut of an undirected graph into two non-empty parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    /// Total weight of the edges between the two parts.
+    pub weight: u64,
+    /// The nodes of one of the parts.
+    pub part: Vec<usize>,
+}
+
+/// The cut with the least total edge weight, by the Stoer-Wagner algorithm.
+///
+/// Every edge must be present in both directions, as added by
+/// [`Graph::add_undirected_edge`]. `None` for graphs with fewer than two
+/// nodes.
+pub fn minimum_cut(graph: &Graph) -> Option<Cut> {
+    // Nodes are merged as the algorithm goes. Each node that is still present
+    // keeps the original nodes it stands for and its summed edge weights.
+    let mut members = (0..graph.len()).map(|node| vec![node]).collect_vec();
+    let mut edges = (0..graph.len())
+        .map(|node| {
+            graph.edges(node).iter().fold(HashMap::new(), |mut edges, (to, weight)| {
+                if *to != node {
+                    *edges.entry(*to).or_insert(0) += weight;
+                }
+                edges
+            })
+        })
+        .collect_vec();
+    let mut present = (0..graph.len()).collect_vec();
+    let mut best: Option<Cut> = None;
+
+    while present.len() > 1 {
+        // Add nodes one by one, always the one most tightly connected to the
+        // nodes added so far. The last two added are `s` and `t`.
+        let mut connection = vec![0u64; graph.len()];
+        let mut added = vec![false; graph.len()];
+        let mut queue = BinaryHeap::from([(0, present[0])]);
+        let mut order = vec![];
+
+        while let Some((weight, node)) = queue.pop() {
+            if added[node] || weight != connection[node] {
+                continue;
+            }
+            added[node] = true;
+            order.push(node);
+            for (next, edge_weight) in &edges[node] {
+                if !added[*next] {
+                    connection[*next] += edge_weight;
+                    queue.push((connection[*next], *next));
+                }
+            }
+        }
+
+        if order.len() < present.len() {
+            // The graph is not connected.
+            let part = order.iter().flat_map(|node| members[*node].clone()).collect_vec();
+            return Some(Cut { weight: 0, part });
+        }
+
+        let t = order[order.len() - 1];
+        let s = order[order.len() - 2];
+
+        // The cut between `t` and everything else is the minimum cut that
+        // separates `s` from `t`.
+        if best.as_ref().is_none_or(|best| connection[t] < best.weight) {
+            best = Some(Cut { weight: connection[t], part: members[t].clone() });
+        }
+
+        // Merge `t` into `s`.
+        let t_members = std::mem::take(&mut members[t]);
+        members[s].extend(t_members);
+        for (next, weight) in std::mem::take(&mut edges[t]) {
+            edges[next].remove(&t);
+            if next != s {
+                *edges[s].entry(next).or_insert(0) += weight;
+                *edges[next].entry(s).or_insert(0) += weight;
+            }
+        }
+        present.retain(|node| *node != t);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_index() {
+        let mut index = NodeIndex::default();
+        assert_eq!(index.index("a"), 0);
+        assert_eq!(index.index("b"), 1);
+        assert_eq!(index.index("a"), 0);
+        assert_eq!(index.get(&"b"), Some(1));
+        assert_eq!(index.get(&"c"), None);
+        assert_eq!(*index.node(1), "b");
+    }
+
+    #[test]
+    fn compress_corridors() {
+        // A ring 0-1-2-3-4-5-0 plus a dead end 3-6, with junctions 0 and 3.
+        let ring = |n: &i32| match n {
+            3 => vec![2, 4, 6],
+            6 => vec![3],
+            n => vec![(n + 5) % 6, (n + 1) % 6],
+        };
+
+        let (graph, index) = compress(&[0, 3], ring);
+
+        assert_eq!(*index.node(1), 3);
+        assert_eq!(graph.edges(0), [(1, 3), (1, 3)]);
+        assert_eq!(graph.edges(1), [(0, 3), (0, 3)]);
+    }
+
+    #[test]
+    fn compress_one_way_corridors() {
+        // 0 -> 1 -> 2, and 2 cannot go back.
+        let line = |n: &i32| match n {
+            0 => vec![1],
+            1 => vec![0, 2],
+            _ => vec![],
+        };
+
+        let (graph, _) = compress(&[0, 2], line);
+
+        assert_eq!(graph.edges(0), [(1, 2)]);
+        assert!(graph.edges(1).is_empty());
+    }
+
+    #[test]
+    fn minimum_cut_between_two_clusters() {
+        // Two triangles joined by a single edge of weight 2.
+        let mut graph = Graph::new(6);
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+            graph.add_undirected_edge(a, b, 3);
+        }
+        graph.add_undirected_edge(2, 3, 2);
+
+        let cut = minimum_cut(&graph).unwrap();
+
+        assert_eq!(cut.weight, 2);
+        let part = cut.part.into_iter().sorted().collect_vec();
+        assert!(part == [0, 1, 2] || part == [3, 4, 5], "{part:?}");
+    }
+
+    #[test]
+    fn minimum_cut_of_disconnected_graph() {
+        let mut graph = Graph::new(3);
+        graph.add_undirected_edge(0, 1, 1);
+
+        assert_eq!(minimum_cut(&graph).unwrap(), Cut { weight: 0, part: vec![0, 1] });
+        assert_eq!(minimum_cut(&Graph::new(1)), None);
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use itertools::Itertools;
+
+use crate::geometry::Direction;
+
+/// `(row, column)` of a cell, with `(0, 0)` in the top left corner.
+pub type Position = (usize, usize);
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the eight neighbours, clockwise starting from up.
+const NEIGHBOURS8: [(isize, isize); 8] =
+    [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+/// The non-empty lines of `input` with surrounding whitespace removed, which
+/// also drops the `\r` of CRLF line endings.
+fn rows(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// A rectangular 2D grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// # Panics
+    ///
+    /// If `cells` does not contain exactly `width * height` elements.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Self { width, height, cells }
+    }
+
+    /// # Panics
+    ///
+    /// If the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows of a grid must have the same length"
+        );
+        Self::new(width, height, rows.into_iter().flatten().collect_vec())
+    }
+
+    /// Parses every non-empty line of the input as a row, mapping each character to a cell.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let rows = rows(input).map(|line| line.chars().map(&mut cell).collect_vec()).collect_vec();
+        Self::from_rows(rows)
+    }
+
+    /// Like [`Grid::parse`], but stops at the first character that cannot be mapped.
+    pub fn try_parse<E>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, E> {
+        let rows = rows(input)
+            .map(|line| line.chars().map(&mut cell).collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T> {
+        (row < self.height && column < self.width)
+            .then(|| &mut self.cells[row * self.width + column])
+    }
+
+    /// Moves `position` by `(rows, columns)`, returning `None` if it leaves the grid.
+    pub fn offset(
+        &self,
+        (row, column): Position,
+        (rows, columns): (isize, isize),
+    ) -> Option<Position> {
+        let row = row.checked_add_signed(rows).filter(|row| *row < self.height)?;
+        let column = column.checked_add_signed(columns).filter(|column| *column < self.width)?;
+        Some((row, column))
+    }
+
+    /// Moves `position` one cell in `direction`, returning `None` if it leaves the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        let vector = direction.vector::<isize>();
+        self.offset(position, (vector.y, vector.x))
+    }
+
+    /// Up, right, down and left neighbours that are inside the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS4.into_iter().filter_map(move |delta| self.offset(position, delta))
+    }
+
+    /// Orthogonal and diagonal neighbours that are inside the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS8.into_iter().filter_map(move |delta| self.offset(position, delta))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Position of the first cell, row by row, that matches the predicate.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    /// Positions of all cells that match the predicate.
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Position> + 'a {
+        self.iter().filter(move |(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect_vec())
+    }
+
+    /// Builds a grid of the given size with the cell at each position computed by `cell`.
+    pub fn from_fn(width: usize, height: usize, cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(cell)
+            .collect_vec();
+        Self::new(width, height, cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transposed(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, column)| self[(column, row)].clone())
+    }
+
+    pub fn rotated_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, column)| {
+            self[(self.height - 1 - column, row)].clone()
+        })
+    }
+
+    pub fn rotated_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, column)| {
+            self[(column, self.width - 1 - row)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flipped_horizontally(&self) -> Self {
+        Self::from_fn(self.width, self.height, |(row, column)| {
+            self[(row, self.width - 1 - column)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flipped_vertically(&self) -> Self {
+        Self::from_fn(self.width, self.height, |(row, column)| {
+            self[(self.height - 1 - row, column)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside of the {}x{} grid", self.width, self.height)
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside of the {width}x{height} grid")
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\r\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn try_parse_reports_invalid_cells() {
+        let parse = |input| Grid::try_parse(input, |c| c.to_digit(10).ok_or(c));
+        assert_eq!(parse("12\n34").unwrap().row(1), [3, 4]);
+        assert_eq!(parse("12\n3x"), Err('x'));
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = grid();
+        assert_eq!(grid.neighbours4((0, 0)).collect_vec(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.step((0, 0), Direction::Right), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(
+            grid.neighbours8((0, 1)).collect_vec(),
+            [(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.rows().collect_vec(), [['a', 'b', 'c'], ['d', 'e', 'f']]);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns().map(|column| column.collect::<String>()).collect_vec(),
+            ["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = grid();
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotated_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flipped_horizontally().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flipped_vertically().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotated_clockwise().rotated_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use itertools::Itertools;
+
+const INPUT_DIR: &str = "./inputs";
+
+/// Overrides [`INPUT_DIR`], so the solutions can be run from any directory.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub enum InputType {
+    Real,
+    Test,
+    Other(String),
+}
+
+impl Display for InputType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            InputType::Real => write!(f, "real"),
+            InputType::Test => write!(f, "test"),
+            InputType::Other(other) => write!(f, "{}", other),
+        }
+    }
+}
+
+impl From<&str> for InputType {
+    fn from(value: &str) -> Self {
+        match value {
+            "real" => InputType::Real,
+            "test" => InputType::Test,
+            other => InputType::Other(other.to_string()),
+        }
+    }
+}
+
+/// Where the puzzle input of a day is read from.
+pub enum InputSource {
+    /// `inputs/dayNN_<label>.txt`
+    Stored(InputType),
+    /// An explicit file, e.g. a generated puzzle.
+    File(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for InputSource {
+    /// `-` reads stdin, anything that looks like a path (contains a separator or
+    /// ends with `.txt`) is read as a file, and everything else is a label.
+    fn from(value: &str) -> Self {
+        if value == "-" {
+            InputSource::Stdin
+        } else if value.contains(['/', '\\']) || value.ends_with(".txt") {
+            InputSource::File(PathBuf::from(value))
+        } else {
+            InputSource::Stored(InputType::from(value))
+        }
+    }
+}
+
+impl From<InputType> for InputSource {
+    fn from(value: InputType) -> Self {
+        InputSource::Stored(value)
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The `real` or `test` input of the day has not been downloaded yet.
+    Missing {
+        day: u8,
+        label: String,
+        available: Vec<String>,
+    },
+    /// There is no input with this custom label for the day.
+    UnknownLabel {
+        day: u8,
+        label: String,
+        available: Vec<String>,
+    },
+    /// The file exists but is not valid UTF-8.
+    Encoding {
+        path: PathBuf,
+    },
+    /// The file exists but contains only whitespace.
+    Empty {
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let available_labels = |available: &[String]| {
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        };
+
+        match self {
+            InputError::Missing { day, label, available } => write!(
+                f,
+                "day {day} has no `{label}` input (expected {}); available inputs: {}",
+                input_path(*day, label).display(),
+                available_labels(available)
+            ),
+            InputError::UnknownLabel { day, label, available } => write!(
+                f,
+                "day {day} has no input labelled `{label}`; available inputs: {}",
+                available_labels(available)
+            ),
+            InputError::Encoding { path } => {
+                write!(f, "{} is not valid UTF-8", path.display())
+            }
+            InputError::Empty { path } => write!(f, "{} is empty", path.display()),
+            InputError::Io { path, source } => {
+                write!(f, "cannot read {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from(INPUT_DIR), PathBuf::from)
+}
+
+fn input_path(day: u8, label: &str) -> PathBuf {
+    input_dir().join(format!("day{day:02}_{label}.txt"))
+}
+
+/// Labels of all input files stored for the day, e.g. `["WJ", "real", "test"]`.
+pub fn available_inputs(day: u8) -> Vec<String> {
+    let prefix = format!("day{day:02}_");
+
+    let Ok(entries) = fs::read_dir(input_dir()) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().into_string().ok()?;
+            let label = file_name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            Some(label.to_string())
+        })
+        .sorted()
+        .collect_vec()
+}
+
+/// Line endings normalized to `\n`, so that a file checked out with CRLF
+/// parses the same way as one with LF.
+fn normalize_line_endings(raw: &str) -> String {
+    raw.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+pub fn read_input(day: u8, input_type: InputType) -> Result<String, InputError> {
+    read_input_from(day, InputSource::Stored(input_type))
+}
+
+pub fn read_input_from(day: u8, source: InputSource) -> Result<String, InputError> {
+    let (path, bytes) = match source {
+        InputSource::Stored(input_type) => {
+            let label = input_type.to_string();
+            let path = input_path(day, &label);
+
+            match fs::read(&path) {
+                Ok(bytes) => (path, bytes),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    let available = available_inputs(day);
+                    return Err(match input_type {
+                        InputType::Real | InputType::Test => {
+                            InputError::Missing { day, label, available }
+                        }
+                        InputType::Other(_) => InputError::UnknownLabel { day, label, available },
+                    });
+                }
+                Err(source) => return Err(InputError::Io { path, source }),
+            }
+        }
+        InputSource::File(path) => match fs::read(&path) {
+            Ok(bytes) => (path, bytes),
+            Err(source) => return Err(InputError::Io { path, source }),
+        },
+        InputSource::Stdin => {
+            let path = PathBuf::from("<stdin>");
+            let mut bytes = vec![];
+            match io::stdin().read_to_end(&mut bytes) {
+                Ok(_) => (path, bytes),
+                Err(source) => return Err(InputError::Io { path, source }),
+            }
+        }
+    };
+
+    let input =
+        String::from_utf8(bytes).map_err(|_| InputError::Encoding { path: path.clone() })?;
+
+    if input.trim().is_empty() {
+        return Err(InputError::Empty { path });
+    }
+
+    Ok(normalize_line_endings(&input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(read_input(0, InputType::Test).unwrap(), "Hello Santa!");
+    }
+
+    #[test]
+    fn crlf_and_lf_inputs_parse_the_same() {
+        let lf = "#.\n.#\n\nab\ncd\n";
+        let crlf = lf.replace('\n', "\r\n");
+
+        assert_eq!(normalize_line_endings(&crlf), lf);
+        assert_eq!(normalize_line_endings("#.\r.#"), "#.\n.#");
+    }
+
+    #[test]
+    fn input_source_from_argument() {
+        assert!(matches!(InputSource::from("-"), InputSource::Stdin));
+        assert!(matches!(InputSource::from("WJ"), InputSource::Stored(InputType::Other(_))));
+        assert!(matches!(InputSource::from("puzzle.txt"), InputSource::File(_)));
+        assert!(matches!(InputSource::from("/tmp/puzzle"), InputSource::File(_)));
+    }
+
+    #[test]
+    fn read_explicit_file() {
+        let source = InputSource::File(PathBuf::from(INPUT_DIR).join("day00_test.txt"));
+        assert_eq!(read_input_from(0, source).unwrap(), "Hello Santa!");
+    }
+
+    #[test]
+    fn missing_explicit_file() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        assert!(matches!(read_input_from(0, source), Err(InputError::Io { .. })));
+    }
+
+    #[test]
+    fn missing_input_lists_available_labels() {
+        match read_input(1, InputType::Other("XY".to_owned())) {
+            Err(InputError::UnknownLabel { available, .. }) => {
+                assert_eq!(available, ["DP", "MN", "WJ", "real", "test", "test2"]);
+            }
+            _ => panic!("expected an unknown label error"),
+        }
+    }
+
+    #[test]
+    fn missing_real_input() {
+        match read_input(0, InputType::Real) {
+            Err(InputError::Missing { available, .. }) => assert_eq!(available, ["test"]),
+            _ => panic!("expected a missing input error"),
+        }
+    }
+}
@@ -0,0 +1,360 @@
+//! Sets of integers stored as sorted half-open ranges, and maps that shift
+//! whole ranges at a time, so that questions about billions of values only
+//! ever look at the ends of the ranges.
+
+use std::{fmt::Debug, ops::Range};
+
+use num::{PrimInt, Signed};
+
+/// A set of values stored as disjoint, non-adjacent half-open ranges in
+/// ascending order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut ranges =
+            ranges.into_iter().filter(|range| range.start < range.end).collect::<Vec<_>>();
+        ranges.sort_by_key(|range| range.start);
+
+        let mut intervals: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match intervals.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => intervals.push(range),
+            }
+        }
+
+        Self { intervals }
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        [range].into_iter().collect()
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges of the set, in ascending order.
+    pub fn intervals(&self) -> &[Range<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end <= value);
+        self.intervals.get(index).is_some_and(|interval| interval.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.intervals.iter().cloned().chain([range]).collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals.iter().chain(&other.intervals).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                intervals.push(start..end);
+            }
+            // Whichever ends first cannot overlap anything further on.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut removed = other.intervals.iter().peekable();
+
+        for interval in &self.intervals {
+            let mut start = interval.start;
+            while let Some(cut) = removed.peek() {
+                if cut.end <= start {
+                    removed.next();
+                    continue;
+                }
+                if cut.start >= interval.end {
+                    break;
+                }
+                if cut.start > start {
+                    intervals.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > interval.end {
+                    break;
+                }
+                removed.next();
+            }
+            if start < interval.end {
+                intervals.push(start..interval.end);
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+
+        for interval in &self.intervals {
+            if interval.end <= at {
+                below.push(interval.clone());
+            } else if interval.start >= at {
+                above.push(interval.clone());
+            } else {
+                below.push(interval.start..at);
+                above.push(at..interval.end);
+            }
+        }
+
+        (Self { intervals: below }, Self { intervals: above })
+    }
+}
+
+/// A map of integers to integers that adds a constant offset to each of a
+/// number of disjoint source ranges, and leaves every other value as it is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PiecewiseShift<T> {
+    /// Disjoint source ranges in ascending order, with their non-zero offset.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for PiecewiseShift<T> {
+    fn default() -> Self {
+        Self { pieces: vec![] }
+    }
+}
+
+impl<T: PrimInt + Signed + Debug> PiecewiseShift<T> {
+    /// The map that changes nothing.
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    /// Shifts the values of `source` by `offset`.
+    ///
+    /// Panics if `source` overlaps a range that is already shifted.
+    pub fn insert(&mut self, source: Range<T>, offset: T) {
+        if source.start >= source.end || offset.is_zero() {
+            return;
+        }
+
+        let index = self.pieces.partition_point(|(range, _)| range.end <= source.start);
+        if let Some((range, _)) = self.pieces.get(index) {
+            assert!(range.start >= source.end, "{source:?} overlaps the shifted range {range:?}");
+        }
+        self.pieces.insert(index, (source, offset));
+    }
+
+    /// The shifted source ranges in ascending order, with their offsets.
+    pub fn pieces(&self) -> &[(Range<T>, T)] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, value: T) -> T {
+        let index = self.pieces.partition_point(|(range, _)| range.end <= value);
+        match self.pieces.get(index) {
+            Some((range, offset)) if range.contains(&value) => value + *offset,
+            _ => value,
+        }
+    }
+
+    /// Every value from the smallest to the largest `T` split into ranges
+    /// shifted by the same offset, including the unshifted gaps between the
+    /// pieces with an offset of zero.
+    fn segments(&self) -> Vec<(Range<T>, T)> {
+        let mut segments = Vec::with_capacity(2 * self.pieces.len() + 1);
+        let mut start = T::min_value();
+
+        for (range, offset) in &self.pieces {
+            if start < range.start {
+                segments.push((start..range.start, T::zero()));
+            }
+            segments.push((range.clone(), *offset));
+            start = range.end;
+        }
+        if start < T::max_value() {
+            segments.push((start..T::max_value(), T::zero()));
+        }
+
+        segments
+    }
+
+    /// The values the map sends the values of `set` to.
+    pub fn image(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let segments = self.segments();
+
+        set.intervals()
+            .iter()
+            .flat_map(|interval| {
+                segments.iter().filter_map(|(range, offset)| {
+                    let start = interval.start.max(range.start);
+                    let end = interval.end.min(range.end);
+                    (start < end).then(|| start + *offset..end + *offset)
+                })
+            })
+            .collect()
+    }
+
+    /// The values the map sends into `set`.
+    pub fn preimage(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        self.segments()
+            .into_iter()
+            .flat_map(|(range, offset)| {
+                // Values that would come from outside the domain of `T`
+                // cannot be sent anywhere.
+                let image_start = range.start.checked_add(&offset).unwrap_or(T::min_value());
+                let image_end = range.end.checked_add(&offset).unwrap_or(T::max_value());
+
+                set.intervals().iter().filter_map(move |interval| {
+                    let start = interval.start.max(image_start);
+                    let end = interval.end.min(image_end);
+                    (start < end).then(|| start - offset..end - offset)
+                })
+            })
+            .collect()
+    }
+
+    /// The map that applies `self` and then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        let next_segments = next.segments();
+        let mut pieces: Vec<(Range<T>, T)> = vec![];
+
+        for (range, offset) in self.segments() {
+            let image = range.start + offset..range.end + offset;
+            for (next_range, next_offset) in &next_segments {
+                let start = image.start.max(next_range.start);
+                let end = image.end.min(next_range.end);
+                if start >= end {
+                    continue;
+                }
+
+                let source = start - offset..end - offset;
+                let total = offset + *next_offset;
+                match pieces.last_mut() {
+                    Some((last, last_offset))
+                        if last.end == source.start && *last_offset == total =>
+                    {
+                        last.end = source.end
+                    }
+                    _ => pieces.push((source, total)),
+                }
+            }
+        }
+
+        pieces.retain(|(_, offset)| !offset.is_zero());
+        Self { pieces }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let merged = set(&[5..8, 0..2, 1..3, 3..4, 10..10]);
+        assert_eq!(merged.intervals(), [0..4, 5..8]);
+        assert!(merged.contains(3));
+        assert!(!merged.contains(4));
+        assert_eq!(merged.min(), Some(0));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+
+        assert_eq!(a.union(&b).intervals(), [0..40]);
+        assert_eq!(a.intersection(&b).intervals(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).intervals(), [0..5, 25..28]);
+        assert_eq!(b.difference(&a).intervals(), [10..20, 30..40]);
+        assert_eq!(a.difference(&set(&[2..4, 6..8])).intervals(), [0..2, 4..6, 8..10, 20..30]);
+
+        let (below, above) = a.split_at(25);
+        assert_eq!(below.intervals(), [0..10, 20..25]);
+        assert_eq!(above.intervals(), [25..30]);
+    }
+
+    #[test]
+    fn shift_values_and_sets() {
+        let mut shift = PiecewiseShift::identity();
+        shift.insert(98..100, -48);
+        shift.insert(50..98, 2);
+
+        assert_eq!(shift.apply(49), 49);
+        assert_eq!(shift.apply(50), 52);
+        assert_eq!(shift.apply(99), 51);
+        assert_eq!(shift.image(&set(&[45..55, 97..99])).intervals(), [45..51, 52..57, 99..100]);
+        assert_eq!(shift.preimage(&set(&[50..53])).intervals(), [50..51, 98..100]);
+        assert_eq!(shift.preimage(&shift.image(&set(&[45..55]))), set(&[45..55]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn overlapping_pieces() {
+        let mut shift = PiecewiseShift::identity();
+        shift.insert(0..10, 1);
+        shift.insert(5..15, 2);
+    }
+
+    #[test]
+    fn composition_agrees_with_applying_in_turn() {
+        let mut first = PiecewiseShift::identity();
+        first.insert(0..10, 100);
+        first.insert(20..30, -15);
+        let mut second = PiecewiseShift::identity();
+        second.insert(5..12, 1);
+        second.insert(100..200, -100);
+
+        let composed = first.then(&second);
+
+        for value in -5..40 {
+            assert_eq!(composed.apply(value), second.apply(first.apply(value)), "{value}");
+        }
+        // 0..10 first goes to 100..110 and then back to 0..10.
+        assert_eq!(composed.apply(7), 7);
+        assert!(composed.pieces().iter().all(|(range, _)| !range.contains(&7)));
+    }
+}
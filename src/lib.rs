@@ -1,34 +1,22 @@
-use std::{
-    fmt::{Display, Formatter, Result},
-    fs, io,
-};
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod parse;
+pub mod polygon;
+pub mod pulse;
+pub mod search;
+pub mod solution;
 
-pub enum InputType {
-    Real,
-    Test,
-    Other(String),
-}
-
-impl Display for InputType {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        match self {
-            InputType::Real => write!(f, "real"),
-            InputType::Test => write!(f, "test"),
-            InputType::Other(other) => write!(f, "{}", other),
-        }
-    }
-}
-
-pub fn read_input(day: u8, input_type: InputType) -> io::Result<String> {
-    fs::read_to_string(format!("./inputs/day{day:02}_{input_type}.txt"))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {
-        assert_eq!(read_input(0, InputType::Test).unwrap(), "Hello Santa!");
-    }
-}
+pub use answers::AnswerBook;
+pub use geometry::{Direction, Point, Point3, Vector, Vector3};
+pub use grid::Grid;
+pub use input::{read_input, read_input_from, InputError, InputSource, InputType};
+pub use parse::ParseError;
+pub use solution::{Answer, Entry, Solution};
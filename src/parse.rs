@@ -0,0 +1,548 @@
+//! A small hand-rolled parser for the line-oriented puzzle inputs.
+//!
+//! A [`Parser`] walks over the whole input and every combinator either
+//! consumes what it expects or fails with a [`ParseError`] that says where in
+//! the input it stopped and what it was looking for. Token parsers skip spaces
+//! and tabs before the token, but never line breaks: those are only consumed
+//! by [`Parser::end_of_line`] and the line-based combinators built on it.
+//!
+//! Every day parses its input through here, so that a malformed input is
+//! reported as a [`ParseError`] pointing at the offending character instead
+//! of a panic in the middle of a part.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    grid::{Grid, Position},
+    Point3, Vector3,
+};
+
+/// Where parsing the input of a day stopped, counted from 1, and what was
+/// expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    /// The line of the input the error is on.
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl Display for ParseError {
+    /// The location and what was expected, followed by the offending line
+    /// with a caret under the column, like a compiler diagnostic:
+    ///
+    /// ```text
+    /// day 4, line 2, column 15: expected `|`
+    ///   |
+    /// 2 | Card 2: 13 32 / 17 61
+    ///   |               ^
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Self { day, line, column, snippet, expected } = self;
+        let gutter = " ".repeat(line.to_string().len());
+        let indent = " ".repeat(column - 1);
+
+        writeln!(f, "day {day}, line {line}, column {column}: expected {expected}")?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {snippet}")?;
+        write!(f, "{gutter} | {indent}^")
+    }
+}
+
+impl Error for ParseError {}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+pub struct Parser<'a> {
+    day: u8,
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// A parser for the input of `day`, which its errors are tagged with.
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self { day, input, offset: 0 }
+    }
+
+    /// The input that is left to parse.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.input.len()
+    }
+
+    /// The line and column of the next character, both counted from 1.
+    pub fn location(&self) -> (usize, usize) {
+        self.location_of(self.offset)
+    }
+
+    fn location_of(&self, position: usize) -> (usize, usize) {
+        let before = &self.input[..position];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
+
+    /// The byte offset of the next character, to report an error there once
+    /// the parser has moved on, see [`Parser::error_at`].
+    pub fn position(&self) -> usize {
+        self.offset
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.offset, expected)
+    }
+
+    /// An error at a `position` taken earlier with [`Parser::position`], for
+    /// what only turns out to be wrong further on, like a name that is used
+    /// before the input defines it.
+    pub fn error_at(&self, position: usize, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.location_of(position);
+        let line_start = self.input[..position].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end =
+            self.input[position..].find('\n').map_or(self.input.len(), |end| position + end);
+        let snippet = self.input[line_start..line_end].trim_end_matches('\r').to_string();
+
+        ParseError { day: self.day, line, column, snippet, expected: expected.into() }
+    }
+
+    /// Skips spaces and tabs, and the `\r` of a CRLF line ending.
+    pub fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start_matches([' ', '\t', '\r']).len();
+    }
+
+    /// Skips any whitespace, line breaks included.
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// The longest run of characters matching `predicate`, which may be empty.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.offset += length;
+        &rest[..length]
+    }
+
+    /// Runs `parse` and rewinds to where it started if it fails.
+    pub fn optional<T>(&mut self, parse: impl FnOnce(&mut Self) -> ParseResult<T>) -> Option<T> {
+        let start = self.offset;
+        match parse(self) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                self.offset = start;
+                None
+            }
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> ParseResult<()> {
+        self.skip_spaces();
+        if self.rest().starts_with(literal) {
+            self.offset += literal.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("`{literal}`")))
+        }
+    }
+
+    /// One of the characters of `choices`.
+    pub fn one_of(&mut self, choices: &str) -> ParseResult<char> {
+        self.skip_spaces();
+        match self.rest().chars().next() {
+            Some(c) if choices.contains(c) => {
+                self.offset += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.error(format!("one of `{choices}`"))),
+        }
+    }
+
+    /// The next character, mapped by `cell`, which returns `None` for
+    /// characters that are not `expected`. Unlike the token parsers, it does
+    /// not skip spaces before the character.
+    pub fn character<T>(
+        &mut self,
+        expected: &str,
+        cell: impl FnOnce(char) -> Option<T>,
+    ) -> ParseResult<T> {
+        match self.rest().chars().next() {
+            Some(c) => match cell(c) {
+                Some(value) => {
+                    self.offset += c.len_utf8();
+                    Ok(value)
+                }
+                None => Err(self.error(expected)),
+            },
+            None => Err(self.error(expected)),
+        }
+    }
+
+    /// A non-empty run of ASCII letters and digits.
+    pub fn word(&mut self) -> ParseResult<&'a str> {
+        self.skip_spaces();
+        match self.take_while(|c| c.is_ascii_alphanumeric()) {
+            "" => Err(self.error("a name")),
+            word => Ok(word),
+        }
+    }
+
+    /// One of the words of `keywords`.
+    pub fn keyword(&mut self, keywords: &[&str]) -> ParseResult<&'a str> {
+        self.skip_spaces();
+        match self.optional(Self::word) {
+            Some(word) if keywords.contains(&word) => Ok(word),
+            other => {
+                if let Some(word) = other {
+                    self.offset -= word.len();
+                }
+                let keywords = keywords.iter().map(|keyword| format!("`{keyword}`")).join(", ");
+                Err(self.error(format!("one of {keywords}")))
+            }
+        }
+    }
+
+    fn number<T: FromStr>(&mut self, sign: bool) -> ParseResult<T> {
+        self.skip_spaces();
+        let start = self.offset;
+        if sign {
+            self.optional(|parser| parser.one_of("+-"));
+        }
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        let number = &self.input[start..self.offset];
+
+        match number.parse() {
+            Ok(value) if !digits.is_empty() => Ok(value),
+            _ => {
+                let expected = if sign { "a signed integer" } else { "an integer" };
+                self.offset = start;
+                Err(self.error(expected))
+            }
+        }
+    }
+
+    /// A non-negative integer.
+    pub fn integer<T: FromStr>(&mut self) -> ParseResult<T> {
+        self.number(false)
+    }
+
+    /// An integer with an optional `+` or `-` sign.
+    pub fn signed<T: FromStr>(&mut self) -> ParseResult<T> {
+        self.number(true)
+    }
+
+    /// One or more items with `separator` between them.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.optional(|parser| parser.literal(separator)).is_some() {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Whitespace-separated items up to the end of the line, possibly none.
+    pub fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        let mut items = vec![];
+        self.skip_spaces();
+        while !self.at_blank_line() {
+            items.push(item(self)?);
+            self.skip_spaces();
+        }
+        Ok(items)
+    }
+
+    /// Whitespace-separated integers up to the first thing on the line that is
+    /// not one, possibly none.
+    pub fn integers<T: FromStr>(&mut self) -> ParseResult<Vec<T>> {
+        let mut integers = vec![];
+        self.skip_spaces();
+        while self.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            integers.push(self.integer()?);
+            self.skip_spaces();
+        }
+        Ok(integers)
+    }
+
+    /// Comma-separated `key=value` pairs.
+    pub fn key_values<T>(
+        &mut self,
+        mut value: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<Vec<(&'a str, T)>> {
+        self.separated(",", |parser| {
+            let key = parser.word()?;
+            parser.literal("=")?;
+            Ok((key, value(parser)?))
+        })
+    }
+
+    /// `name -> a, b`: a name and the names it points to.
+    pub fn edge(&mut self) -> ParseResult<(&'a str, Vec<&'a str>)> {
+        let name = self.word()?;
+        self.literal("->")?;
+        let targets = self.separated(",", Self::word)?;
+        Ok((name, targets))
+    }
+
+    /// `x,y,z`, with optional spaces after the commas.
+    pub fn point3<T: FromStr>(&mut self) -> ParseResult<Point3<T>> {
+        let x = self.signed()?;
+        self.literal(",")?;
+        let y = self.signed()?;
+        self.literal(",")?;
+        Ok(Point3::new(x, y, self.signed()?))
+    }
+
+    /// `px, py, pz @ vx, vy, vz`: a position and a velocity.
+    pub fn vector3<T: FromStr>(&mut self) -> ParseResult<(Point3<T>, Vector3<T>)> {
+        let position = self.point3()?;
+        self.literal("@")?;
+        let Point3 { x, y, z } = self.point3()?;
+        Ok((position, Vector3::new(x, y, z)))
+    }
+
+    /// The end of the current line, or of the input.
+    pub fn end_of_line(&mut self) -> ParseResult<()> {
+        self.skip_spaces();
+        if self.is_at_end() {
+            Ok(())
+        } else if self.rest().starts_with('\n') {
+            self.offset += 1;
+            Ok(())
+        } else {
+            Err(self.error("the end of the line"))
+        }
+    }
+
+    fn at_blank_line(&self) -> bool {
+        let rest = self.rest().trim_start_matches([' ', '\t', '\r']);
+        rest.is_empty() || rest.starts_with('\n')
+    }
+
+    /// One item per line, up to the next blank line or the end of the input.
+    pub fn lines<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        let mut items = vec![];
+        while !self.at_blank_line() {
+            items.push(item(self)?);
+            self.end_of_line()?;
+        }
+        Ok(items)
+    }
+
+    /// The lines up to the next blank line or the end of the input as the rows
+    /// of a grid, mapping each character to a cell. `cell` returns `None` for
+    /// characters that are not `expected`.
+    pub fn grid<T>(
+        &mut self,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<Grid<T>> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        while !self.at_blank_line() {
+            self.skip_spaces();
+            let mut row = vec![];
+            while self.rest().starts_with(|c: char| !c.is_whitespace()) {
+                row.push(self.character(expected, &mut cell)?);
+            }
+            if let Some(width) = rows.first().map(Vec::len).filter(|width| *width != row.len()) {
+                return Err(self.error(format!("a row of {width} cells")));
+            }
+            rows.push(row);
+            self.end_of_line()?;
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    /// Blocks of lines separated by blank lines, up to the end of the input.
+    pub fn blocks<T>(
+        &mut self,
+        mut block: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        let mut blocks = vec![];
+        self.skip_whitespace();
+        while !self.is_at_end() {
+            blocks.push(block(self)?);
+            self.skip_whitespace();
+        }
+        Ok(blocks)
+    }
+}
+
+/// Parses the whole of the input of `day` with `parse`, ignoring whitespace
+/// around it.
+pub fn parse<'a, T>(
+    day: u8,
+    input: &'a str,
+    parse: impl FnOnce(&mut Parser<'a>) -> ParseResult<T>,
+) -> ParseResult<T> {
+    let mut parser = Parser::new(day, input);
+    parser.skip_whitespace();
+    let value = parse(&mut parser)?;
+    parser.skip_whitespace();
+    if parser.is_at_end() {
+        Ok(value)
+    } else {
+        Err(parser.error("the end of the input"))
+    }
+}
+
+/// Parses every line of the input of `day` with `item`.
+pub fn lines<'a, T>(
+    day: u8,
+    input: &'a str,
+    item: impl FnMut(&mut Parser<'a>) -> ParseResult<T>,
+) -> ParseResult<Vec<T>> {
+    parse(day, input, |parser| parser.lines(item))
+}
+
+/// Parses the input of `day` as a single grid, see [`Parser::grid`].
+pub fn grid<T>(
+    day: u8,
+    input: &str,
+    expected: &str,
+    cell: impl FnMut(char) -> Option<T>,
+) -> ParseResult<Grid<T>> {
+    parse(day, input, |parser| parser.grid(expected, cell))
+}
+
+/// An error at a cell of the grid that [`grid`] parses from the input of
+/// `day`, for a character that is valid on its own but not where it is.
+pub fn cell_error(
+    day: u8,
+    input: &str,
+    (row, column): Position,
+    expected: impl Into<String>,
+) -> ParseError {
+    let mut parser = Parser::new(day, input);
+    parser.skip_whitespace();
+    for _ in 0..row {
+        parser.take_while(|c| c != '\n');
+        parser.optional(|parser| parser.literal("\n"));
+    }
+    parser.skip_spaces();
+    parser.offset += parser.rest().chars().take(column).map(char::len_utf8).sum::<usize>();
+    parser.error(expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(parse(0, "  42 ", Parser::integer::<u32>), Ok(42));
+        assert_eq!(parse(0, "-7", Parser::signed::<i64>), Ok(-7));
+        assert_eq!(parse(0, "41 48  83\n", Parser::integers::<u8>), Ok(vec![41, 48, 83]));
+        assert_eq!(
+            parse(0, "0 -3 6", |parser| parser.list(Parser::signed::<i8>)),
+            Ok(vec![0, -3, 6])
+        );
+
+        let error = parse(0, "-7", Parser::integer::<u32>).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "an integer");
+        assert!(parse(0, "300", Parser::integer::<u8>).is_err());
+    }
+
+    #[test]
+    fn combinators() {
+        let ratings = parse(19, "x=787,m=2655", |parser| parser.key_values(Parser::integer::<u32>));
+        assert_eq!(ratings, Ok(vec![("x", 787), ("m", 2655)]));
+
+        let edge = parse(20, "broadcaster -> a, b, c", Parser::edge);
+        assert_eq!(edge, Ok(("broadcaster", vec!["a", "b", "c"])));
+
+        let color = parse(2, "green", |parser| parser.keyword(&["red", "green", "blue"]));
+        assert_eq!(color, Ok("green"));
+        let error = parse(2, " pink", |parser| parser.keyword(&["red", "blue"])).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (2, "one of `red`, `blue`"));
+
+        let hailstone = parse(24, "19, 13, 30 @ -2,  1, -2", Parser::vector3::<i64>);
+        assert_eq!(hailstone, Ok((Point3::new(19, 13, 30), Vector3::new(-2, 1, -2))));
+    }
+
+    #[test]
+    fn lines_and_blocks() {
+        let input = "1 2\r\n3\n\n4\n";
+        let blocks =
+            parse(0, input, |parser| parser.blocks(|parser| parser.lines(Parser::integers)));
+        assert_eq!(blocks, Ok(vec![vec![vec![1, 2], vec![3]], vec![vec![4]]]));
+    }
+
+    #[test]
+    fn grids() {
+        let cell = |c| matches!(c, '.' | '#').then_some(c == '#');
+        let parsed = grid(0, "#.\n.#\n", "`.` or `#`", cell).unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (2, 2));
+        assert!(parsed[(1, 1)]);
+
+        let error = parse(0, "#.\n.#\n\n.x", |parser| {
+            parser.blocks(|parser| parser.grid("`.` or `#`", cell))
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (4, 2, ".x"));
+        let error = grid(0, "#.\n.", "`.` or `#`", cell).unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 2, "a row of 2 cells"));
+
+        let error = cell_error(0, "\n#.\n.#\n", (1, 1), "`.`");
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (3, 2, ".#"));
+    }
+
+    #[test]
+    fn error_location() {
+        let error = lines(20, "a -> b\nc -> d\ne => f", Parser::edge).unwrap_err();
+        let expected = ParseError {
+            day: 20,
+            line: 3,
+            column: 3,
+            snippet: "e => f".to_owned(),
+            expected: "`->`".to_owned(),
+        };
+        assert_eq!(error, expected);
+    }
+
+    #[test]
+    fn caret_under_the_column() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 32 / 17 61\n";
+        let error = lines(4, input, |parser| {
+            parser.literal("Card")?;
+            parser.integer::<u32>()?;
+            parser.literal(":")?;
+            parser.integers::<u32>()?;
+            parser.literal("|")?;
+            parser.integers::<u32>()
+        })
+        .unwrap_err();
+
+        let expected = "\
+day 4, line 2, column 15: expected `|`
+  |
+2 | Card 2: 13 32 / 17 61
+  |               ^";
+        assert_eq!(error.to_string(), expected);
+    }
+}
@@ -0,0 +1,145 @@
+//! Simple polygons with integer vertices, given as the list of their corners
+//! in order around the outline. The last corner connects back to the first.
+
+use std::fmt::Display;
+
+use itertools::Itertools;
+use num::{Integer, PrimInt, Signed};
+
+use crate::Point;
+
+fn edges<T: Copy>(vertices: &[Point<T>]) -> impl Iterator<Item = (Point<T>, Point<T>)> + '_ {
+    vertices.iter().copied().zip(vertices.iter().copied().cycle().skip(1))
+}
+
+/// Twice the area enclosed by the polygon, by the shoelace formula. Doubled
+/// so that it is always an integer. `None` if that overflows `T`.
+pub fn checked_double_area<T: PrimInt + Signed>(vertices: &[Point<T>]) -> Option<T> {
+    let sum = edges(vertices).try_fold(T::zero(), |sum, (a, b)| {
+        sum.checked_add(&a.x.checked_mul(&b.y)?)?.checked_sub(&b.x.checked_mul(&a.y)?)
+    })?;
+
+    if sum < T::zero() {
+        T::zero().checked_sub(&sum)
+    } else {
+        Some(sum)
+    }
+}
+
+/// Number of integer points on the outline of the polygon. `None` if that
+/// overflows `T`.
+pub fn checked_boundary_points<T: PrimInt + Signed + Integer>(vertices: &[Point<T>]) -> Option<T> {
+    edges(vertices).try_fold(T::zero(), |sum, (a, b)| {
+        let dx = b.x.checked_sub(&a.x)?;
+        let dy = b.y.checked_sub(&a.y)?;
+        sum.checked_add(&dx.gcd(&dy))
+    })
+}
+
+/// Number of integer points inside or on the outline of the polygon, by
+/// Pick's theorem: `area = interior + boundary / 2 - 1`. `None` if that
+/// overflows `T`.
+pub fn checked_enclosed_points<T: PrimInt + Signed + Integer>(vertices: &[Point<T>]) -> Option<T> {
+    let two = T::one() + T::one();
+    let double_area = checked_double_area(vertices)?;
+    let boundary = checked_boundary_points(vertices)?;
+
+    // interior + boundary = (2 * area + boundary + 2) / 2
+    double_area.checked_add(&boundary)?.checked_add(&two).map(|sum| sum / two)
+}
+
+const OVERFLOW: &str = "the polygon is too large for its coordinate type";
+
+/// Twice the area enclosed by the polygon, by the shoelace formula. Doubled
+/// so that it is always an integer.
+pub fn double_area<T: PrimInt + Signed>(vertices: &[Point<T>]) -> T {
+    checked_double_area(vertices).expect(OVERFLOW)
+}
+
+/// Number of integer points on the outline of the polygon.
+pub fn boundary_points<T: PrimInt + Signed + Integer>(vertices: &[Point<T>]) -> T {
+    checked_boundary_points(vertices).expect(OVERFLOW)
+}
+
+/// Number of integer points strictly inside the polygon, by Pick's theorem.
+pub fn interior_points<T: PrimInt + Signed + Integer>(vertices: &[Point<T>]) -> T {
+    enclosed_points(vertices) - boundary_points(vertices)
+}
+
+/// Number of integer points inside or on the outline of the polygon.
+pub fn enclosed_points<T: PrimInt + Signed + Integer>(vertices: &[Point<T>]) -> T {
+    checked_enclosed_points(vertices).expect(OVERFLOW)
+}
+
+/// An SVG document showing the outline of the polygon, `y` growing down as in
+/// [`Point`].
+pub fn svg<T: PrimInt + Signed + Display>(vertices: &[Point<T>]) -> String {
+    let min_x = vertices.iter().map(|vertex| vertex.x).min().unwrap_or(T::zero());
+    let min_y = vertices.iter().map(|vertex| vertex.y).min().unwrap_or(T::zero());
+    let max_x = vertices.iter().map(|vertex| vertex.x).max().unwrap_or(T::zero());
+    let max_y = vertices.iter().map(|vertex| vertex.y).max().unwrap_or(T::zero());
+    let points = vertices.iter().map(|vertex| format!("{},{}", vertex.x, vertex.y)).join(" ");
+
+    // Leave a margin of one unit around the outline so the stroke is not cut
+    // off at the edges.
+    let one = T::one();
+    let two = one + one;
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n  \
+         <polygon points=\"{points}\" fill=\"lightblue\" stroke=\"black\" \
+         stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>\n</svg>",
+        min_x - one,
+        min_y - one,
+        max_x - min_x + two,
+        max_y - min_y + two,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coordinates: &[(i64, i64)]) -> Vec<Point<i64>> {
+        coordinates.iter().map(|(x, y)| Point::new(*x, *y)).collect()
+    }
+
+    #[test]
+    fn rectangle() {
+        let rectangle = points(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+
+        assert_eq!(double_area(&rectangle), 24);
+        assert_eq!(boundary_points(&rectangle), 14);
+        assert_eq!(interior_points(&rectangle), 6);
+        assert_eq!(enclosed_points(&rectangle), 20);
+    }
+
+    #[test]
+    fn overflow() {
+        let huge = [(0, 0), (i64::MAX, 0), (i64::MAX, i64::MAX), (0, i64::MAX)];
+        let huge = points(&huge);
+
+        assert_eq!(checked_double_area(&huge), None);
+        assert_eq!(checked_enclosed_points(&huge), None);
+        assert_eq!(checked_boundary_points(&points(&[(i64::MIN, 0), (i64::MAX, 0)])), None);
+    }
+
+    #[test]
+    fn svg_outline() {
+        let triangle = points(&[(0, 0), (4, 0), (0, 4)]);
+        let svg = svg(&triangle);
+
+        assert!(svg.contains("viewBox=\"-1 -1 6 6\""), "{svg}");
+        assert!(svg.contains("points=\"0,0 4,0 0,4\""), "{svg}");
+    }
+
+    #[test]
+    fn orientation_does_not_matter() {
+        let clockwise = points(&[(0, 0), (4, 0), (0, 4)]);
+        let counterclockwise = clockwise.iter().rev().copied().collect::<Vec<_>>();
+
+        assert_eq!(double_area(&clockwise), 16);
+        assert_eq!(double_area(&counterclockwise), 16);
+        assert_eq!(interior_points(&clockwise), 3);
+        assert_eq!(interior_points(&counterclockwise), 3);
+    }
+}
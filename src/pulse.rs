@@ -0,0 +1,398 @@
+//! The pulse propagation network of 2023 day 20: flip-flops, conjunctions and
+//! a broadcaster sending high and low pulses to each other.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use itertools::Itertools;
+use num::integer::lcm;
+
+use crate::{parse, ParseError};
+
+/// The kind of a module, from the prefix of its name in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+/// A network of modules wired together, with a button that sends a low pulse
+/// to the broadcaster.
+pub struct Machine {
+    broadcaster: Box<dyn Module>,
+    modules: HashMap<String, Box<dyn Module>>,
+    low_pulses: usize,
+    high_pulses: usize,
+    presses: usize,
+}
+
+impl Machine {
+    /// The network described by `input`, one `%name -> outputs` line per
+    /// module. Parse errors are reported as errors in the input of `day`.
+    pub fn new_from_input(day: u8, input: &str) -> Result<Self, ParseError> {
+        let parsed_input = parse::parse(day, input, |parser| {
+            let parsed_input = parser.lines(|parser| {
+                let module_type = match parser.optional(|parser| parser.one_of("%&")) {
+                    Some('%') => ModuleType::FlipFlop,
+                    Some(_) => ModuleType::Conjunction,
+                    None => ModuleType::Broadcaster,
+                };
+                let (module_name, outputs) = parser.edge()?;
+                let outputs = outputs.into_iter().map(str::to_string).collect_vec();
+
+                Ok((module_name.to_string(), module_type, outputs))
+            })?;
+
+            if parsed_input.iter().any(|(module_name, ..)| module_name == "broadcaster") {
+                Ok(parsed_input)
+            } else {
+                Err(parser.error("a `broadcaster -> ...` line"))
+            }
+        })?;
+
+        let mut modules = parsed_input
+            .iter()
+            .map(|(module_name, module_type, _outputs)| {
+                let module: Box<dyn Module> = match module_type {
+                    ModuleType::FlipFlop => Box::new(FlipFlop::new(module_name.clone())),
+                    ModuleType::Conjunction => Box::new(Conjunction::new(module_name.clone())),
+                    ModuleType::Broadcaster => Box::new(Broadcaster::new(module_name.clone())),
+                };
+
+                (module, module_name)
+            })
+            .fold(HashMap::new(), |mut map, (module, name)| {
+                map.insert(name.clone(), module);
+                map
+            });
+
+        for (module_name, _module_type, outputs) in parsed_input.iter() {
+            let mut to_add = Vec::new();
+            {
+                let module = modules.get_mut(module_name).unwrap();
+                for output in outputs.iter() {
+                    module.add_output(output);
+                    to_add.push((output.clone(), module_name.clone()));
+                }
+            }
+            for (output, module_name) in to_add {
+                let output_module = modules.get_mut(&output);
+                if let Some(output_module) = output_module {
+                    output_module.add_input(&module_name)
+                };
+            }
+        }
+
+        let broadcaster = modules.remove("broadcaster").unwrap();
+
+        Ok(Machine { broadcaster, modules, low_pulses: 0, high_pulses: 0, presses: 0 })
+    }
+
+    pub fn push_button(&mut self) {
+        self.push_button_with(|_, _, _| {});
+    }
+
+    /// Pushes the button once and calls `observer` with the sender, receiver
+    /// and kind of every pulse sent until the network settles, in order.
+    pub fn push_button_with(&mut self, mut observer: impl FnMut(&str, &str, Pulse)) {
+        let mut queue = VecDeque::new();
+
+        self.presses += 1;
+        self.low_pulses += 1;
+        observer("button", &self.broadcaster.get_name(), Pulse::Low);
+
+        queue.extend(self.broadcaster.process_signal(Pulse::Low, "button".to_string()));
+
+        while let Some((from, to, pulse)) = queue.pop_front() {
+            match pulse {
+                Pulse::High => self.high_pulses += 1,
+                Pulse::Low => self.low_pulses += 1,
+            }
+            observer(&from, &to, pulse);
+
+            if let Some(module) = self.modules.get_mut(&to) {
+                queue.extend(module.process_signal(pulse, from));
+            }
+        }
+    }
+
+    pub fn low_pulses(&self) -> usize {
+        self.low_pulses
+    }
+
+    pub fn high_pulses(&self) -> usize {
+        self.high_pulses
+    }
+
+    /// Number of times the button has been pushed.
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// Names of the modules with an output to `name`.
+    pub fn inputs_of(&self, name: &str) -> Vec<String> {
+        self.modules
+            .values()
+            .chain([&self.broadcaster])
+            .filter(|module| module.get_outputs().iter().any(|output| output == name))
+            .map(|module| module.get_name())
+            .sorted()
+            .collect_vec()
+    }
+
+    pub fn module(&self, name: &str) -> Option<&dyn Module> {
+        if name == self.broadcaster.get_name() {
+            return Some(self.broadcaster.as_ref());
+        }
+        self.modules.get(name).map(|module| module.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+pub trait Module {
+    fn get_name(&self) -> String;
+
+    fn get_type(&self) -> ModuleType;
+
+    fn get_outputs(&self) -> &[String];
+
+    fn add_output(&mut self, input_name: &str);
+
+    fn add_input(&mut self, input_name: &str);
+
+    fn process_signal(&mut self, pulse: Pulse, from: String) -> Vec<(String, String, Pulse)>;
+}
+
+#[derive(Debug)]
+pub struct Broadcaster {
+    name: String,
+    outputs: Vec<String>,
+}
+
+impl Broadcaster {
+    pub fn new(name: String) -> Self {
+        Self { name, outputs: vec![] }
+    }
+}
+
+impl Module for Broadcaster {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_type(&self) -> ModuleType {
+        ModuleType::Broadcaster
+    }
+
+    fn get_outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    fn add_output(&mut self, input_name: &str) {
+        self.outputs.push(input_name.to_string());
+    }
+
+    fn add_input(&mut self, _input_name: &str) {}
+
+    fn process_signal(&mut self, pulse: Pulse, _from: String) -> Vec<(String, String, Pulse)> {
+        self.outputs.iter().map(|output| (self.name.clone(), output.clone(), pulse)).collect_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct FlipFlop {
+    name: String,
+    state: bool,
+    outputs: Vec<String>,
+}
+
+impl FlipFlop {
+    pub fn new(name: String) -> Self {
+        Self { name, state: false, outputs: vec![] }
+    }
+}
+
+impl Module for FlipFlop {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_type(&self) -> ModuleType {
+        ModuleType::FlipFlop
+    }
+
+    fn get_outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    fn add_output(&mut self, input_name: &str) {
+        self.outputs.push(input_name.to_string());
+    }
+
+    fn add_input(&mut self, _input_name: &str) {}
+
+    fn process_signal(&mut self, pulse: Pulse, _from: String) -> Vec<(String, String, Pulse)> {
+        if let Pulse::High = pulse {
+            return vec![];
+        }
+
+        let pulse_to_send = if !self.state { Pulse::High } else { Pulse::Low };
+        self.state = !self.state;
+
+        self.outputs
+            .iter()
+            .map(|output| (self.name.clone(), output.clone(), pulse_to_send))
+            .collect_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct Conjunction {
+    name: String,
+    inputs_last_pulse: HashMap<String, Pulse>,
+    outputs: Vec<String>,
+}
+
+impl Conjunction {
+    pub fn new(name: String) -> Self {
+        Self { name, inputs_last_pulse: HashMap::new(), outputs: vec![] }
+    }
+}
+
+impl Module for Conjunction {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_type(&self) -> ModuleType {
+        ModuleType::Conjunction
+    }
+
+    fn get_outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    fn add_output(&mut self, input_name: &str) {
+        self.outputs.push(input_name.to_string());
+    }
+
+    fn add_input(&mut self, input_name: &str) {
+        self.inputs_last_pulse.insert(input_name.to_string(), Pulse::Low);
+    }
+
+    fn process_signal(&mut self, pulse: Pulse, from: String) -> Vec<(String, String, Pulse)> {
+        self.inputs_last_pulse.insert(from, pulse);
+
+        if self.inputs_last_pulse.iter().all(|(_input, pulse)| *pulse == Pulse::High) {
+            self.outputs
+                .iter()
+                .map(|output| (self.name.clone(), output.clone(), Pulse::Low))
+                .collect_vec()
+        } else {
+            self.outputs
+                .iter()
+                .map(|output| (self.name.clone(), output.clone(), Pulse::High))
+                .collect_vec()
+        }
+    }
+}
+
+/// Most button presses [`presses_until_low`] tries before giving up.
+const MAX_PRESSES: usize = 1_000_000;
+
+/// Fewest button presses until `target` gets a low pulse, counted from the
+/// fresh `machine`, whose button must not have been pushed yet.
+///
+/// Simulating that directly takes far too long for the puzzle inputs. They
+/// are built so that `target` is fed by a single conjunction, whose inputs
+/// each send it a high pulse once every so many presses, starting from the
+/// first period. The conjunction sends a low pulse once all of them do so in
+/// the same press, which is at the least common multiple of their periods.
+///
+/// `None` if `target` is not fed by a single conjunction, or if one of its
+/// inputs does not send a high pulse within [`MAX_PRESSES`] presses.
+pub fn presses_until_low(mut machine: Machine, target: &str) -> Option<usize> {
+    assert_eq!(machine.presses(), 0, "the periods are counted from the first press");
+    let [feeder] = machine.inputs_of(target).try_into().ok()?;
+    if machine.module(&feeder)?.get_type() != ModuleType::Conjunction {
+        return None;
+    }
+
+    let mut pending: HashSet<String> = machine.inputs_of(&feeder).into_iter().collect();
+    let mut periods = vec![];
+
+    while !pending.is_empty() && machine.presses() < MAX_PRESSES {
+        let press = machine.presses() + 1;
+        machine.push_button_with(|from, to, pulse| {
+            if to == feeder && pulse == Pulse::High && pending.remove(from) {
+                periods.push(press);
+            }
+        });
+    }
+
+    if !pending.is_empty() {
+        return None;
+    }
+    periods.into_iter().reduce(lcm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two conjunctions feeding `rx` through `feed`, one of them high every
+    /// second press and the other every fourth press.
+    const NETWORK: &str = "\
+broadcaster -> a, x
+%a -> inva
+&inva -> feed
+%x -> y
+%y -> invy
+&invy -> feed
+&feed -> rx";
+
+    #[test]
+    fn observe_pulses() {
+        let mut machine = Machine::new_from_input(20, NETWORK).unwrap();
+        let mut pulses = vec![];
+        machine.push_button_with(|from, to, pulse| {
+            pulses.push((from.to_owned(), to.to_owned(), pulse))
+        });
+
+        assert_eq!(pulses[0], ("button".to_owned(), "broadcaster".to_owned(), Pulse::Low));
+        assert!(pulses.contains(&("inva".to_owned(), "feed".to_owned(), Pulse::Low)));
+        assert_eq!(machine.low_pulses() + machine.high_pulses(), pulses.len());
+        assert_eq!(machine.presses(), 1);
+    }
+
+    #[test]
+    fn analysis_agrees_with_simulation() {
+        let mut machine = Machine::new_from_input(20, NETWORK).unwrap();
+        let mut low_to_rx = false;
+        while !low_to_rx {
+            machine.push_button_with(|_, to, pulse| low_to_rx |= to == "rx" && pulse == Pulse::Low);
+        }
+
+        assert_eq!(machine.presses(), 4);
+        assert_eq!(presses_until_low(Machine::new_from_input(20, NETWORK).unwrap(), "rx"), Some(4));
+    }
+
+    #[test]
+    fn analysis_needs_a_conjunction() {
+        let machine = Machine::new_from_input(20, "broadcaster -> a\n%a -> rx").unwrap();
+        assert_eq!(presses_until_low(machine, "rx"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "counted from the first press")]
+    fn analysis_needs_a_fresh_machine() {
+        let mut machine = Machine::new_from_input(20, NETWORK).unwrap();
+        machine.push_button();
+        presses_until_low(machine, "rx");
+    }
+}
@@ -0,0 +1,262 @@
+//! Shortest path searches over an implicit state space.
+//!
+//! The states are never stored up front: every search starts from a single
+//! state and asks a `successors` closure for the neighbours of each state it
+//! visits, so the same functions work for grids, grids with extra state such as
+//! a heading, or infinite spaces.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A route found by a search, from the start state to the goal state inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+const NO_PARENT: usize = usize::MAX;
+
+/// States seen so far, each with the index of the state it was reached from.
+struct Visited<S, V> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    /// The best known value (cost or depth) and parent of every state.
+    best: Vec<(V, usize)>,
+}
+
+impl<S: Clone + Hash + Eq, V: Copy> Visited<S, V> {
+    fn new(start: S, value: V) -> Self {
+        Self {
+            states: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            best: vec![(value, NO_PARENT)],
+        }
+    }
+
+    /// Records `state` as reached from `parent` if it is new or `improves`
+    /// returns true for its current value, and returns its index if so.
+    fn update(
+        &mut self,
+        state: S,
+        value: V,
+        parent: usize,
+        improves: impl FnOnce(V) -> bool,
+    ) -> Option<usize> {
+        match self.indices.entry(state) {
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push(entry.key().clone());
+                entry.insert(index);
+                self.best.push((value, parent));
+                Some(index)
+            }
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                improves(self.best[index].0).then(|| {
+                    self.best[index] = (value, parent);
+                    index
+                })
+            }
+        }
+    }
+
+    fn path_to(&self, mut index: usize) -> Vec<S> {
+        let mut states = vec![];
+        while index != NO_PARENT {
+            states.push(self.states[index].clone());
+            index = self.best[index].1;
+        }
+        states.reverse();
+        states
+    }
+}
+
+/// Cheapest path from `start` to the first state that satisfies `is_goal`.
+///
+/// `successors` returns the neighbours of a state together with the cost of
+/// moving there. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but visits states in order of their cost plus
+/// `heuristic`, an estimate of the remaining cost that must never be too high.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start, C::default());
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > visited.best[index].0 {
+            // A cheaper way to this state was found after this entry was queued.
+            continue;
+        }
+
+        if is_goal(&visited.states[index]) {
+            return Some(Path { cost, states: visited.path_to(index) });
+        }
+
+        for (next, step_cost) in successors(&visited.states[index]) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next) = visited.update(next, next_cost, index, |best| next_cost < best) {
+                queue.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Path with the fewest steps from `start` to the first state that satisfies
+/// `is_goal`.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    let mut queue = VecDeque::from([0]);
+    let mut visited = Visited::new(start, ());
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&visited.states[index]) {
+            return Some(visited.path_to(index));
+        }
+
+        for next in successors(&visited.states[index]) {
+            if let Some(next) = visited.update(next, (), index, |_| false) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Number of steps to every state reachable from `start`.
+pub fn bfs_distances<S, I>(start: S, successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    bfs_distances_within(start, usize::MAX, successors)
+}
+
+/// Number of steps to every state reachable from `start` in at most
+/// `max_depth` steps. Useful when the state space is infinite.
+pub fn bfs_distances_within<S, I>(
+    start: S,
+    max_depth: usize,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if distance == max_depth {
+            continue;
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back((entry.key().clone(), distance + 1));
+                entry.insert(distance + 1);
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::Position, Grid};
+
+    /// Digits are the cost of entering a cell, `#` is a wall.
+    fn grid() -> Grid<char> {
+        Grid::parse("1119\n9#19\n9111\n99#1", |c| c)
+    }
+
+    fn weighted_successors(
+        grid: &Grid<char>,
+    ) -> impl FnMut(&Position) -> Vec<(Position, u32)> + '_ {
+        |position| {
+            grid.neighbours4(*position)
+                .filter_map(|next| grid[next].to_digit(10).map(|cost| (next, cost)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn dijkstra_finds_cheapest_path() {
+        let grid = grid();
+        let path = dijkstra((0, 0), weighted_successors(&grid), |p| *p == (3, 3)).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states, [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let grid = grid();
+        let goal: Position = (3, 3);
+        let manhattan = |(y, x): &Position| (goal.0.abs_diff(*y) + goal.1.abs_diff(*x)) as u32;
+        let path = astar((0, 0), weighted_successors(&grid), manhattan, |p| *p == goal).unwrap();
+        assert_eq!(path.cost, 6);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let grid = grid();
+        assert_eq!(dijkstra((0, 0), weighted_successors(&grid), |p| *p == (1, 1)), None);
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let grid = grid();
+        let open = |position: &Position| {
+            grid.neighbours4(*position).filter(|next| grid[*next] != '#').collect::<Vec<_>>()
+        };
+
+        let path = bfs((0, 0), open, |p| *p == (3, 3)).unwrap();
+        assert_eq!(path.len(), 7);
+
+        let distances = bfs_distances((0, 0), open);
+        assert_eq!(distances.len(), 14);
+        assert_eq!(distances[&(3, 0)], 3);
+        assert_eq!(distances[&(3, 3)], 6);
+
+        assert_eq!(bfs_distances_within((0, 0), 2, open).len(), 5);
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::ParseError;
+
+/// The answer to a part of a puzzle.
+///
+/// Integers of every width convert to [`Answer::Integer`], except `u128` values
+/// that do not fit in an `i128`, which are kept as text. Equality is tolerant of
+/// representation, so `Answer::from("42") == Answer::from(42)`.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+impl Answer {
+    fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(value) => Some(*value),
+            Answer::Text(text) => text.trim().parse().ok(),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(left), Some(right)) => left == right,
+            _ => self.to_string().trim() == other.to_string().trim(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i128::try_from(value) {
+                        Ok(value) => Answer::Integer(value),
+                        Err(_) => Answer::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A puzzle solution for a single day.
+///
+/// Alternate implementations of the same day implement this trait too and are
+/// registered under a variant name (see [`Entry::variant`]).
+pub trait Solution {
+    const DAY: u8;
+
+    fn part1(input: &str) -> Answer;
+    fn part2(input: &str) -> Answer;
+
+    /// Parses the input without solving anything. The parts assume the input
+    /// has been checked, so the runner calls this first to report a malformed
+    /// input with its location instead of panicking.
+    fn check(input: &str) -> Result<(), ParseError>;
+
+    /// A picture of how a part was solved, e.g. the grid with the chosen route
+    /// drawn in. `None` for days that have nothing to draw.
+    fn render(_input: &str, _part: u8) -> Option<String> {
+        None
+    }
+}
+
+/// Type-erased [`Solution`] as stored in the registry.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    pub variant: Option<&'static str>,
+    pub part1: fn(&str) -> Answer,
+    pub part2: fn(&str) -> Answer,
+    pub check: fn(&str) -> Result<(), ParseError>,
+    pub render: fn(&str, u8) -> Option<String>,
+}
+
+impl Entry {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            variant: None,
+            part1: S::part1,
+            part2: S::part2,
+            check: S::check,
+            render: S::render,
+        }
+    }
+
+    pub fn variant<S: Solution>(name: &'static str) -> Self {
+        Self { variant: Some(name), ..Self::of::<S>() }
+    }
+
+    pub fn name(&self) -> String {
+        format!("day{:02}{}", self.day, self.variant.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_and_integer_answers_compare_equal() {
+        assert_eq!(Answer::from("42"), Answer::from(42u8));
+        assert_eq!(Answer::from(" 42\n".to_string()), Answer::from(42usize));
+        assert_ne!(Answer::from("43"), Answer::from(42i64));
+        assert_eq!(Answer::from("abc"), Answer::from("abc"));
+    }
+
+    #[test]
+    fn integers_of_every_width() {
+        assert_eq!(Answer::from(-7i8), Answer::Integer(-7));
+        assert_eq!(Answer::from(173152345887206u128), Answer::Integer(173152345887206));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    parse::{self, ParseResult, Parser},
+    Answer, ParseError, Solution,
+};
+
+const DAY: u8 = 0;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = DAY;
+
+    fn part1(input: &str) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &str) -> Answer {
+        part2(input).into()
+    }
+
+    fn check(input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+}
+
+/// One item per line. `Parser::word` stands in for the parser of a line.
+fn parse_input(input: &str) -> ParseResult<Vec<&str>> {
+    parse::lines(DAY, input, Parser::word)
+}
+
+fn part1(input: &str) -> String {
+    let lines = parse_input(input).unwrap();
+    todo!()
+}
+
+fn part2(input: &str) -> String {
+    let lines = parse_input(input).unwrap();
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, InputType};
+
+    fn get_test_input() -> String {
+        read_input(DAY, InputType::Test).unwrap()
+    }
+
+    #[test]
+    fn part1_test() {
+        let expected = "";
+        let result = part1(&get_test_input());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_test() {
+        let expected = "";
+        let result = part2(&get_test_input());
+        assert_eq!(result, expected);
+    }
+}